@@ -28,4 +28,169 @@ pub enum VaultError {
 
     #[msg("Invalid exchange rate: denominator must be > 0")]
     InvalidDenominator,
+
+    #[msg("Required SOL exceeds the maximum the buyer is willing to pay")]
+    SlippageExceeded,
+
+    #[msg("Invalid price curve parameters")]
+    InvalidPriceCurve,
+
+    #[msg("Purchase spans too many price curve steps")]
+    CurveStepLimitExceeded,
+
+    #[msg("Invalid price tier schedule")]
+    InvalidPriceTiers,
+
+    #[msg("Price tiers that have already been sold cannot be changed")]
+    TierAlreadySold,
+
+    #[msg("Purchase exceeds the supply covered by the price tiers")]
+    PriceTiersExhausted,
+
+    #[msg("Price feed account is missing or does not match the vault's feed")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price is not available")]
+    InvalidOraclePrice,
+
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+
+    #[msg("Oracle price confidence interval is too wide")]
+    OracleConfidenceTooWide,
+
+    #[msg("VAL sale has not started")]
+    SaleNotStarted,
+
+    #[msg("VAL sale has ended")]
+    SaleEnded,
+
+    #[msg("Invalid sale window: start must be before end")]
+    InvalidSaleWindow,
+
+    #[msg("Purchases are restricted to allowlisted wallets")]
+    AllowlistRequired,
+
+    #[msg("Invalid allowlist proof")]
+    InvalidAllowlistProof,
+
+    #[msg("Purchase exceeds the wallet's allocation")]
+    ExceedsAllocation,
+
+    #[msg("Purchase exceeds the per-wallet VAL limit")]
+    ExceedsWalletLimit,
+
+    #[msg("Purchase exceeds the per-transaction SOL limit")]
+    ExceedsTransactionLimit,
+
+    #[msg("Vesting accounts are required while vesting is enabled")]
+    VestingAccountsRequired,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("No vested VAL to claim")]
+    NothingToClaim,
+
+    #[msg("Invalid token metadata: name, symbol or URI is empty or too long")]
+    InvalidMetadata,
+
+    #[msg("Invalid transfer fee: basis points must be <= 10000")]
+    InvalidTransferFee,
+
+    #[msg("Invalid decimals: VAL mint decimals must be <= 18")]
+    InvalidDecimals,
+
+    #[msg("Max supply is not a whole number of VAL at the mint's decimals")]
+    MaxSupplyNotRepresentable,
+
+    #[msg("Mint exceeds the reserved supply")]
+    ExceedsReservedSupply,
+
+    #[msg("Reserved supply is below the VAL already minted by the admin")]
+    InvalidReservedSupply,
+
+    #[msg("Recipient accounts must match the amounts and hold the VAL mint")]
+    InvalidRecipients,
+
+    #[msg("VAL supply is finalized; no more VAL can be minted")]
+    SupplyFinalized,
+
+    #[msg("The sale must have ended before the supply can be finalized")]
+    SaleNotEnded,
+
+    #[msg("Invalid max supply: decreases cannot go below total minted and increases must be queued")]
+    InvalidMaxSupply,
+
+    #[msg("No max supply increase is pending")]
+    NoPendingMaxSupply,
+
+    #[msg("The timelock on this change has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Rate change exceeds the maximum allowed per update")]
+    RateChangeTooLarge,
+
+    #[msg("Rate was updated too recently")]
+    RateUpdateTooSoon,
+
+    #[msg("Rate is outside the configured bounds")]
+    RateOutOfBounds,
+
+    #[msg("Invalid rate limits")]
+    InvalidRateLimits,
+
+    #[msg("No rate limits change is pending")]
+    NoPendingRateLimits,
+
+    #[msg("Scheduled rate changes must take effect in the future")]
+    InvalidRateSchedule,
+
+    #[msg("Payment mint is not enabled for VAL purchases")]
+    PaymentMintDisabled,
+
+    #[msg("Token purchases are unavailable while purchases vest")]
+    VestingNotSupported,
+
+    #[msg("Deposit mint is not enabled for child deposits")]
+    DepositMintDisabled,
+
+    #[msg("Deposit is below the vault minimum")]
+    DepositBelowMinimum,
+
+    #[msg("Deposit exceeds the per-child deposit limit")]
+    ExceedsChildDepositLimit,
+
+    #[msg("Deposit exceeds the vault's total deposit limit")]
+    ExceedsTotalDepositLimit,
+
+    #[msg("Invalid deposit limits: the minimum exceeds a maximum")]
+    InvalidDepositLimits,
+
+    #[msg("Invalid lock duration")]
+    InvalidLockDuration,
+
+    #[msg("Payout exceeds the child's unlocked balance")]
+    FundsLocked,
+
+    #[msg("A valid attestation is required")]
+    AttestationRequired,
+
+    #[msg("Invalid attestation")]
+    InvalidAttestation,
+
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+
+    #[msg("Child account is frozen")]
+    ChildAccountFrozen,
+
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+
+    #[msg("Purchase would mint zero VAL")]
+    ZeroValOutput,
+
+    #[msg("Oracle pricing cannot be enabled while rate limits are set")]
+    RateLimitsActive,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{PriceCurve, PriceTier, RateLimits, RoundingMode};
+
 #[event]
 pub struct VaultInitialized {
     pub admin: Pubkey,
@@ -9,11 +11,16 @@ pub struct VaultInitialized {
 
 #[event]
 pub struct DepositMade {
+    /// Wallet that funded the deposit.
     pub depositor: Pubkey,
     pub vault: Pubkey,
     pub child: Pubkey,
     pub amount: u64,
     pub child_total_deposited: u64,
+    /// Wallet that owns the credited child account.
+    pub beneficiary: Pubkey,
+    /// Unix timestamp until which this deposit stays locked (0 = unlocked).
+    pub locked_until: i64,
 }
 
 #[event]
@@ -48,6 +55,8 @@ pub struct ValMintInitialized {
     pub admin: Pubkey,
     pub vault: Pubkey,
     pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub decimals: u8,
 }
 
 #[event]
@@ -57,6 +66,9 @@ pub struct ValPurchased {
     pub sol_amount: u64,
     pub val_amount: u64,
     pub total_minted: u64,
+    pub rounding_remainder: u64,
+    pub effective_val_per_sol: u64,
+    pub vested: bool,
 }
 
 #[event]
@@ -68,3 +80,291 @@ pub struct RateUpdated {
     pub new_numerator: u64,
     pub new_denominator: u64,
 }
+
+#[event]
+pub struct RoundingModeUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub old_mode: RoundingMode,
+    pub new_mode: RoundingMode,
+}
+
+#[event]
+pub struct PriceCurveUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub old_curve: PriceCurve,
+    pub new_curve: PriceCurve,
+}
+
+#[event]
+pub struct PriceTiersUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub tiers: Vec<PriceTier>,
+}
+
+#[event]
+pub struct SaleWindowUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub sale_start: i64,
+    pub sale_end: i64,
+}
+
+#[event]
+pub struct AllowlistRootUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub allowlist_root: [u8; 32],
+}
+
+#[event]
+pub struct PurchaseLimitsUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub max_val_per_wallet: u64,
+    pub max_sol_per_tx: u64,
+}
+
+#[event]
+pub struct VestingScheduleUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub vesting_start: i64,
+    pub vesting_cliff: i64,
+    pub vesting_duration: i64,
+}
+
+#[event]
+pub struct VestedValClaimed {
+    pub beneficiary: Pubkey,
+    pub vault: Pubkey,
+    pub position: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
+
+#[event]
+pub struct ValMetadataUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct ValTransferFeesHarvested {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReservedSupplyUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub reserved_supply: u64,
+}
+
+#[event]
+pub struct ValAdminMinted {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reserved_minted: u64,
+    pub total_minted: u64,
+}
+
+#[event]
+pub struct ValSupplyFinalized {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub total_minted: u64,
+}
+
+#[event]
+pub struct MaxSupplyUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub old_max_supply: u64,
+    pub new_max_supply: u64,
+}
+
+#[event]
+pub struct MaxSupplyIncreaseQueued {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub pending_max_supply: u64,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct RateLimitsProposed {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub limits: RateLimits,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct RateLimitsUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub old_limits: RateLimits,
+    pub new_limits: RateLimits,
+}
+
+#[event]
+pub struct RateChangeScheduled {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub new_numerator: u64,
+    pub new_denominator: u64,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct PaymentMintUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub val_per_token_numerator: u64,
+    pub val_per_token_denominator: u64,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct ValPurchasedWithToken {
+    pub user: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub token_amount: u64,
+    pub val_amount: u64,
+    pub rounding_remainder: u64,
+    pub total_minted: u64,
+}
+
+#[event]
+pub struct AdminTokenWithdrawal {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DepositMintUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct TokenDepositMade {
+    pub depositor: Pubkey,
+    pub vault: Pubkey,
+    pub child: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub child_total_deposited: u64,
+    /// Unix timestamp until which this deposit stays locked (0 = unlocked).
+    pub locked_until: i64,
+}
+
+#[event]
+pub struct TokenPayoutRequested {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub child: Pubkey,
+    pub mint: Pubkey,
+    pub payout: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TokenPayoutExecuted {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub child: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub child_total_paid_out: u64,
+}
+
+#[event]
+pub struct DepositLimitsUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub min_deposit: u64,
+    pub max_deposit_per_child: u64,
+    pub max_total_deposits: u64,
+}
+
+#[event]
+pub struct MinLockDurationUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub min_lock_duration: i64,
+}
+
+#[event]
+pub struct AttestorUpdated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub attestor: Pubkey,
+}
+
+#[event]
+pub struct AttestationIssued {
+    pub attestor: Pubkey,
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AttestationRevoked {
+    pub attestor: Pubkey,
+    pub vault: Pubkey,
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct ChildFrozen {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub child: Pubkey,
+    pub reason: u8,
+}
+
+#[event]
+pub struct ChildUnfrozen {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub child: Pubkey,
+}
+
+#[event]
+pub struct VaultMigrated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub len: u64,
+}
+
+#[event]
+pub struct ChildMigrated {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub child: Pubkey,
+    pub len: u64,
+}
@@ -20,6 +20,7 @@ pub struct AdminExecutePayout<'info> {
     #[account(
         mut,
         constraint = child.vault == vault.key() @ VaultError::Unauthorized,
+        constraint = !child.frozen @ VaultError::ChildAccountFrozen,
     )]
     pub child: Account<'info, ChildAccount>,
 
@@ -60,6 +61,10 @@ pub fn handler(ctx: Context<AdminExecutePayout>) -> Result<()> {
         .ok_or(VaultError::MathOverflow)?;
 
     require!(payout.amount <= remaining, VaultError::ExceedsAllowedPayout);
+    require!(
+        payout.amount <= child.unlocked_balance(Clock::get()?.unix_timestamp)?,
+        VaultError::FundsLocked
+    );
     require!(
         ctx.accounts.treasury.lamports() >= payout.amount,
         VaultError::InvalidAmount
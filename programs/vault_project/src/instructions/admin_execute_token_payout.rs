@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::VaultError;
+use crate::events::TokenPayoutExecuted;
+use crate::state::{ChildAccount, ChildTokenBalance, PendingTokenPayout, Vault, CHILD_TOKEN_SEED};
+
+#[derive(Accounts)]
+pub struct AdminExecuteTokenPayout<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        constraint = child.vault == vault.key() @ VaultError::Unauthorized,
+        constraint = !child.frozen @ VaultError::ChildAccountFrozen,
+    )]
+    pub child: Account<'info, ChildAccount>,
+
+    #[account(
+        mut,
+        seeds = [CHILD_TOKEN_SEED, child.key().as_ref(), mint.key().as_ref()],
+        bump = child_token_balance.bump,
+    )]
+    pub child_token_balance: Account<'info, ChildTokenBalance>,
+
+    #[account(
+        mut,
+        constraint = payout.vault == vault.key() @ VaultError::Unauthorized,
+        constraint = payout.child == child.key() @ VaultError::Unauthorized,
+        constraint = payout.mint == mint.key() @ VaultError::Unauthorized,
+    )]
+    pub payout: Account<'info, PendingTokenPayout>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Treasury PDA that owns the token treasury. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Recipient must be the child's authority.
+    #[account(
+        constraint = recipient.key() == child.authority @ VaultError::Unauthorized,
+    )]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AdminExecuteTokenPayout>) -> Result<()> {
+    let payout = &ctx.accounts.payout;
+    let balance = &ctx.accounts.child_token_balance;
+
+    require!(!payout.executed, VaultError::AlreadyExecuted);
+
+    let remaining = balance
+        .total_deposited
+        .checked_sub(balance.total_paid_out)
+        .ok_or(VaultError::MathOverflow)?;
+
+    require!(payout.amount <= remaining, VaultError::ExceedsAllowedPayout);
+    require!(
+        payout.amount <= balance.unlocked_balance(Clock::get()?.unix_timestamp)?,
+        VaultError::FundsLocked
+    );
+    require!(
+        ctx.accounts.treasury_token_ata.amount >= payout.amount,
+        VaultError::InvalidAmount
+    );
+
+    // PDA-signed transfer: treasury token account → recipient's ATA
+    let vault_key = ctx.accounts.vault.key();
+    let treasury_seeds: &[&[u8]] = &[
+        Vault::TREASURY_SEED,
+        vault_key.as_ref(),
+        &[ctx.accounts.vault.treasury_bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_token_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_ata.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        payout.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let balance = &mut ctx.accounts.child_token_balance;
+    balance.total_paid_out = balance
+        .total_paid_out
+        .checked_add(payout.amount)
+        .ok_or(VaultError::MathOverflow)?;
+
+    let payout = &mut ctx.accounts.payout;
+    payout.executed = true;
+
+    emit!(TokenPayoutExecuted {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_key,
+        child: ctx.accounts.child.key(),
+        mint: payout.mint,
+        recipient: ctx.accounts.recipient.key(),
+        amount: payout.amount,
+        child_total_paid_out: ctx.accounts.child_token_balance.total_paid_out,
+    });
+
+    Ok(())
+}
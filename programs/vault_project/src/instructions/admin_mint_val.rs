@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::ValAdminMinted;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct AdminMintVal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Wallet receiving the VAL. Can be any valid pubkey.
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = val_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_val_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AdminMintVal>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let accounts = ctx.accounts;
+    accounts.vault.record_admin_mint(amount)?;
+
+    mint_val(
+        &accounts.vault,
+        &accounts.token_program,
+        &accounts.val_mint,
+        &accounts.mint_authority,
+        ctx.bumps.mint_authority,
+        accounts.recipient_val_ata.to_account_info(),
+        amount,
+    )?;
+
+    emit!(ValAdminMinted {
+        admin: accounts.admin.key(),
+        vault: accounts.vault.key(),
+        recipient: accounts.recipient.key(),
+        destination: accounts.recipient_val_ata.key(),
+        amount,
+        reserved_minted: accounts.vault.reserved_minted,
+        total_minted: accounts.vault.total_minted,
+    });
+
+    Ok(())
+}
+
+/// Mints `amount` VAL to `destination`, signed by the mint_authority PDA.
+pub(crate) fn mint_val<'info>(
+    vault: &Account<'info, Vault>,
+    token_program: &Interface<'info, TokenInterface>,
+    val_mint: &InterfaceAccount<'info, Mint>,
+    mint_authority: &UncheckedAccount<'info>,
+    mint_authority_bump: u8,
+    destination: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let vault_key = vault.key();
+    let mint_authority_seeds: &[&[u8]] = &[
+        MINT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[mint_authority_bump],
+    ];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: val_mint.to_account_info(),
+                to: destination,
+                authority: mint_authority.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        amount,
+    )
+}
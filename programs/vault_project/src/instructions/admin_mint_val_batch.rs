@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::ValAdminMinted;
+use crate::instructions::admin_mint_val::mint_val;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct AdminMintValBatch<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Mints `amounts[i]` VAL to `recipients[i]`, whose VAL ATA is the i-th
+/// remaining account. Recipient ATAs must already exist.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AdminMintValBatch<'info>>,
+    recipients: Vec<Pubkey>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    require!(
+        !amounts.is_empty()
+            && amounts.len() == recipients.len()
+            && amounts.len() == ctx.remaining_accounts.len(),
+        VaultError::InvalidRecipients
+    );
+
+    let accounts = ctx.accounts;
+    let val_mint_key = accounts.val_mint.key();
+    let token_program_key = accounts.token_program.key();
+
+    for ((destination, recipient), &amount) in ctx
+        .remaining_accounts
+        .iter()
+        .zip(recipients.iter())
+        .zip(amounts.iter())
+    {
+        require!(amount > 0, VaultError::InvalidAmount);
+        let ata = get_associated_token_address_with_program_id(
+            recipient,
+            &val_mint_key,
+            &token_program_key,
+        );
+        require_keys_eq!(destination.key(), ata, VaultError::InvalidRecipients);
+
+        accounts.vault.record_admin_mint(amount)?;
+
+        mint_val(
+            &accounts.vault,
+            &accounts.token_program,
+            &accounts.val_mint,
+            &accounts.mint_authority,
+            ctx.bumps.mint_authority,
+            destination.clone(),
+            amount,
+        )?;
+
+        emit!(ValAdminMinted {
+            admin: accounts.admin.key(),
+            vault: accounts.vault.key(),
+            recipient: *recipient,
+            destination: destination.key(),
+            amount,
+            reserved_minted: accounts.vault.reserved_minted,
+            total_minted: accounts.vault.total_minted,
+        });
+    }
+
+    Ok(())
+}
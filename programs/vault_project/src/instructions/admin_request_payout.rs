@@ -19,6 +19,7 @@ pub struct AdminRequestPayout<'info> {
 
     #[account(
         constraint = child.vault == vault.key() @ VaultError::Unauthorized,
+        constraint = !child.frozen @ VaultError::ChildAccountFrozen,
     )]
     pub child: Account<'info, ChildAccount>,
 
@@ -42,14 +43,17 @@ pub fn handler(ctx: Context<AdminRequestPayout>, amount: u64, nonce: u64) -> Res
         .checked_sub(child.total_paid_out)
         .ok_or(VaultError::MathOverflow)?;
 
+    let now = Clock::get()?.unix_timestamp;
+
     require!(amount > 0, VaultError::InvalidAmount);
     require!(amount <= remaining, VaultError::ExceedsAllowedPayout);
+    require!(amount <= child.unlocked_balance(now)?, VaultError::FundsLocked);
 
     let payout = &mut ctx.accounts.payout;
     payout.vault = ctx.accounts.vault.key();
     payout.child = child.key();
     payout.amount = amount;
-    payout.requested_at = Clock::get()?.unix_timestamp;
+    payout.requested_at = now;
     payout.executed = false;
     payout.bump = ctx.bumps.payout;
 
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::TokenPayoutRequested;
+use crate::state::{
+    ChildAccount, ChildTokenBalance, PendingTokenPayout, Vault, CHILD_TOKEN_SEED, TOKEN_PAYOUT_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
+pub struct AdminRequestTokenPayout<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        constraint = child.vault == vault.key() @ VaultError::Unauthorized,
+        constraint = !child.frozen @ VaultError::ChildAccountFrozen,
+    )]
+    pub child: Account<'info, ChildAccount>,
+
+    #[account(
+        seeds = [CHILD_TOKEN_SEED, child.key().as_ref(), child_token_balance.mint.as_ref()],
+        bump = child_token_balance.bump,
+    )]
+    pub child_token_balance: Account<'info, ChildTokenBalance>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PendingTokenPayout::LEN,
+        seeds = [
+            TOKEN_PAYOUT_SEED,
+            vault.key().as_ref(),
+            child.key().as_ref(),
+            child_token_balance.mint.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub payout: Account<'info, PendingTokenPayout>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AdminRequestTokenPayout>, amount: u64, nonce: u64) -> Result<()> {
+    let balance = &ctx.accounts.child_token_balance;
+
+    let remaining = balance
+        .total_deposited
+        .checked_sub(balance.total_paid_out)
+        .ok_or(VaultError::MathOverflow)?;
+
+    require!(amount > 0, VaultError::InvalidAmount);
+    require!(amount <= remaining, VaultError::ExceedsAllowedPayout);
+    require!(
+        amount <= balance.unlocked_balance(Clock::get()?.unix_timestamp)?,
+        VaultError::FundsLocked
+    );
+
+    let payout = &mut ctx.accounts.payout;
+    payout.vault = ctx.accounts.vault.key();
+    payout.child = ctx.accounts.child.key();
+    payout.mint = balance.mint;
+    payout.amount = amount;
+    payout.requested_at = Clock::get()?.unix_timestamp;
+    payout.executed = false;
+    payout.bump = ctx.bumps.payout;
+
+    emit!(TokenPayoutRequested {
+        admin: ctx.accounts.admin.key(),
+        vault: ctx.accounts.vault.key(),
+        child: payout.child,
+        mint: payout.mint,
+        payout: payout.key(),
+        amount,
+    });
+
+    // Suppress unused variable warning
+    let _ = nonce;
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::VaultError;
+use crate::events::AdminTokenWithdrawal;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct AdminWithdrawToken<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Treasury PDA that owns the token treasury. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Must match vault.admin_destination. Tokens only go to its ATA.
+    #[account(
+        constraint = admin_destination.key() == vault.admin_destination @ VaultError::Unauthorized,
+    )]
+    pub admin_destination: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = admin_destination,
+        associated_token::token_program = token_program,
+    )]
+    pub destination_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AdminWithdrawToken>, amount: u64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+    require!(
+        ctx.accounts.treasury_token_ata.amount >= amount,
+        VaultError::InsufficientFunds
+    );
+
+    // PDA-signed transfer: treasury token account → admin_destination's ATA
+    let vault_key = ctx.accounts.vault.key();
+    let treasury_seeds: &[&[u8]] = &[
+        Vault::TREASURY_SEED,
+        vault_key.as_ref(),
+        &[ctx.accounts.vault.treasury_bump],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_token_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination_token_ata.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(AdminTokenWithdrawal {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_key,
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination_token_ata.key(),
+        amount,
+    });
+
+    Ok(())
+}
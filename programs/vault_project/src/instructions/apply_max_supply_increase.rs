@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::MaxSupplyUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct ApplyMaxSupplyIncrease<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+pub fn handler(ctx: Context<ApplyMaxSupplyIncrease>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let old_max_supply = vault.max_supply;
+
+    vault.apply_max_supply_increase(Clock::get()?.unix_timestamp)?;
+
+    emit!(MaxSupplyUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        old_max_supply,
+        new_max_supply: vault.max_supply,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::RateLimitsUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct ApplyRateLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+pub fn handler(ctx: Context<ApplyRateLimits>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let old_limits = vault.rate_limits;
+
+    vault.apply_rate_limits(Clock::get()?.unix_timestamp)?;
+
+    emit!(RateLimitsUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        old_limits,
+        new_limits: vault.rate_limits,
+    });
+
+    Ok(())
+}
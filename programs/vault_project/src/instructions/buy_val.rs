@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::VaultError;
-use crate::events::ValPurchased;
-use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+use crate::events::{RateUpdated, ValPurchased};
+use crate::instructions::admin_mint_val::mint_val;
+use crate::oracle::OraclePrice;
+use crate::pricing;
+use crate::state::{
+    Attestation, PriceCurve, PurchaseRecord, Vault, VestingPosition, ATTESTATION_SEED,
+    MINT_AUTHORITY_SEED, PURCHASE_RECORD_SEED, VAL_MINT_SEED, VESTING_ESCROW_SEED, VESTING_SEED,
+};
 
 #[derive(Accounts)]
 pub struct BuyVal<'info> {
@@ -17,7 +23,7 @@ pub struct BuyVal<'info> {
         seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
         bump = vault.vault_bump,
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: Box<Account<'info, Vault>>,
 
     /// CHECK: Treasury PDA that holds SOL. Validated by seeds derivation.
     #[account(
@@ -31,8 +37,9 @@ pub struct BuyVal<'info> {
         mut,
         seeds = [VAL_MINT_SEED, vault.key().as_ref()],
         bump,
+        mint::token_program = token_program,
     )]
-    pub val_mint: Account<'info, Mint>,
+    pub val_mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
     #[account(
@@ -46,83 +53,261 @@ pub struct BuyVal<'info> {
         payer = user,
         associated_token::mint = val_mint,
         associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub user_val_ata: Account<'info, TokenAccount>,
+    pub user_val_ata: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    /// Buyer's running purchase totals. Created on the buyer's first purchase,
+    /// whose rent the buyer pays, even when no allowlist or wallet cap is set,
+    /// so caps configured later still count earlier purchases.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PurchaseRecord::LEN,
+        seeds = [PURCHASE_RECORD_SEED, vault.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    /// Buyer's vesting position, required while the vault vests purchases.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = VestingPosition::LEN,
+        seeds = [VESTING_SEED, vault.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub vesting_position: Option<Account<'info, VestingPosition>>,
+
+    /// Escrow holding vesting VAL, required while the vault vests purchases.
+    #[account(
+        mut,
+        seeds = [VESTING_ESCROW_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub vesting_escrow: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: SOL/USD price feed, required when the vault uses oracle pricing.
+    /// Key is checked against the vault's configured feed and the data is parsed as Pyth v2.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Attestation for `user`, required while the vault has an attestor.
+    #[account(
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), user.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<BuyVal>, sol_lamports: u64) -> Result<()> {
-    let vault = &ctx.accounts.vault;
-
-    // Validations
-    require!(sol_lamports > 0, VaultError::InvalidAmount);
-    require!(vault.val_per_sol_denominator > 0, VaultError::InvalidDenominator);
-
-    // Calculate VAL amount: val_amount = sol_lamports * numerator / denominator
-    // Using checked math to prevent overflow
-    let val_amount = sol_lamports
-        .checked_mul(vault.val_per_sol_numerator)
-        .ok_or(VaultError::MathOverflow)?
-        .checked_div(vault.val_per_sol_denominator)
-        .ok_or(VaultError::MathOverflow)?;
-
-    // Check max supply constraint
-    let new_total_minted = vault
-        .total_minted
-        .checked_add(val_amount)
-        .ok_or(VaultError::MathOverflow)?;
-    require!(
-        new_total_minted <= vault.max_supply,
-        VaultError::ExceedsMaxSupply
-    );
-
-    // Transfer SOL from user to treasury
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        ),
-        sol_lamports,
-    )?;
-
-    // PDA-signed mint: mint_authority signs the mint_to CPI
-    let vault_key = ctx.accounts.vault.key();
-    let mint_authority_seeds: &[&[u8]] = &[
-        MINT_AUTHORITY_SEED,
-        vault_key.as_ref(),
-        &[ctx.bumps.mint_authority],
-    ];
-
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.val_mint.to_account_info(),
-                to: ctx.accounts.user_val_ata.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            &[mint_authority_seeds],
-        ),
-        val_amount,
-    )?;
-
-    // Update total_minted in vault state
-    let vault = &mut ctx.accounts.vault;
-    vault.total_minted = new_total_minted;
-
-    emit!(ValPurchased {
-        user: ctx.accounts.user.key(),
-        vault: vault.key(),
-        sol_amount: sol_lamports,
-        val_amount,
-        total_minted: vault.total_minted,
-    });
-
-    Ok(())
+    ctx.accounts.buy(sol_lamports, &ctx.bumps)
+}
+
+impl<'info> BuyVal<'info> {
+    /// Buys VAL for exactly `sol_lamports` from the buyer's wallet.
+    pub(crate) fn buy(&mut self, sol_lamports: u64, bumps: &BuyValBumps) -> Result<()> {
+        // Switch to a scheduled rate once it has taken effect
+        let now = Clock::get()?.unix_timestamp;
+        self.apply_scheduled_rate(now)?;
+        let vault = &self.vault;
+
+        // Validations
+        require!(sol_lamports > 0, VaultError::InvalidAmount);
+        vault.require_sale_open(now)?;
+        require!(!vault.allowlist_enabled(), VaultError::AllowlistRequired);
+
+        // Calculate VAL amount from the vault's pricing curve, using u128
+        // intermediates and the vault's rounding mode.
+        let oracle_price = self.oracle_price()?;
+        let quote = pricing::quote_val_for_sol(vault, sol_lamports, oracle_price.as_ref())?;
+
+        self.settle(sol_lamports, quote.amount, quote.remainder, bumps)
+    }
+
+    /// Applies the vault's scheduled rate change if it is due, emitting `RateUpdated`.
+    pub(crate) fn apply_scheduled_rate(&mut self, now: i64) -> Result<()> {
+        let vault = &mut self.vault;
+        let old_numerator = vault.val_per_sol_numerator;
+        let old_denominator = vault.val_per_sol_denominator;
+
+        if vault.apply_scheduled_rate(now) {
+            emit!(RateUpdated {
+                admin: vault.admin_authority,
+                vault: vault.key(),
+                old_numerator,
+                old_denominator,
+                new_numerator: vault.val_per_sol_numerator,
+                new_denominator: vault.val_per_sol_denominator,
+            });
+        }
+        Ok(())
+    }
+
+    /// Loads and validates the feed price when the vault uses oracle pricing.
+    pub(crate) fn oracle_price(&self) -> Result<Option<OraclePrice>> {
+        let PriceCurve::Oracle {
+            price_feed,
+            max_price_age,
+            max_confidence_bps,
+            ..
+        } = self.vault.price_curve
+        else {
+            return Ok(None);
+        };
+
+        let feed = self
+            .price_feed
+            .as_ref()
+            .ok_or(VaultError::InvalidPriceFeed)?;
+        require_keys_eq!(feed.key(), price_feed, VaultError::InvalidPriceFeed);
+
+        let price = OraclePrice::from_pyth_account(&feed.try_borrow_data()?)?;
+        price.validate(Clock::get()?.unix_timestamp, max_price_age, max_confidence_bps)?;
+
+        Ok(Some(price))
+    }
+
+    /// Moves `sol_lamports` from the buyer into the treasury, mints exactly
+    /// `val_amount` VAL to the buyer's ATA (or the vesting escrow while purchases
+    /// vest) and records the purchase, enforcing the max supply and purchase
+    /// limits. `rounding_remainder` is reported in `ValPurchased`.
+    pub(crate) fn settle(
+        &mut self,
+        sol_lamports: u64,
+        val_amount: u64,
+        rounding_remainder: u64,
+        bumps: &BuyValBumps,
+    ) -> Result<()> {
+        // Never take SOL for a quote that rounded down to nothing
+        require!(val_amount > 0, VaultError::ZeroValOutput);
+        self.vault.require_attestation(
+            &self.user.key(),
+            self.attestation.as_deref(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // Check max supply constraint, keeping reserved supply for admin mints
+        self.vault.record_sale_mint(val_amount)?;
+
+        // Check purchase limits
+        let vault = &self.vault;
+        require!(
+            vault.max_sol_per_tx == 0 || sol_lamports <= vault.max_sol_per_tx,
+            VaultError::ExceedsTransactionLimit
+        );
+        let wallet_val_purchased = self
+            .purchase_record
+            .val_purchased
+            .checked_add(val_amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            vault.max_val_per_wallet == 0 || wallet_val_purchased <= vault.max_val_per_wallet,
+            VaultError::ExceedsWalletLimit
+        );
+
+        // Vesting purchases are minted into escrow and tracked on the buyer's position
+        let vested = vault.vesting_enabled();
+        let destination = if vested {
+            require!(
+                self.vesting_position.is_some(),
+                VaultError::VestingAccountsRequired
+            );
+            self.vesting_escrow
+                .as_ref()
+                .ok_or(VaultError::VestingAccountsRequired)?
+                .to_account_info()
+        } else {
+            self.user_val_ata.to_account_info()
+        };
+
+        // Transfer SOL from user to treasury
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.user.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                },
+            ),
+            sol_lamports,
+        )?;
+
+        mint_val(
+            &self.vault,
+            &self.token_program,
+            &self.val_mint,
+            &self.mint_authority,
+            bumps.mint_authority,
+            destination,
+            val_amount,
+        )?;
+        let vault_key = self.vault.key();
+
+        // If this purchase record was just initialized, set its fields
+        let record = &mut self.purchase_record;
+        if record.vault == Pubkey::default() {
+            record.vault = vault_key;
+            record.user = self.user.key();
+            record.val_purchased = 0;
+            record.sol_spent = 0;
+            record.bump = bumps.purchase_record;
+        }
+        record.val_purchased = wallet_val_purchased;
+        record.sol_spent = record
+            .sol_spent
+            .checked_add(sol_lamports)
+            .ok_or(VaultError::MathOverflow)?;
+
+        if vested {
+            let position = self
+                .vesting_position
+                .as_mut()
+                .ok_or(VaultError::VestingAccountsRequired)?;
+
+            // If this position was just initialized, set its fields
+            if position.vault == Pubkey::default() {
+                position.vault = vault_key;
+                position.beneficiary = self.user.key();
+                position.total_amount = 0;
+                position.claimed_amount = 0;
+                position.vested_base = 0;
+                position.bump = bumps
+                    .vesting_position
+                    .ok_or(VaultError::VestingAccountsRequired)?;
+            }
+
+            // Each purchase re-anchors the position on the vault's current schedule
+            let vault = &self.vault;
+            let now = Clock::get()?.unix_timestamp;
+            let start_ts = if vault.vesting_start > 0 {
+                vault.vesting_start
+            } else {
+                now
+            };
+            position.add_purchase(
+                val_amount,
+                start_ts,
+                vault.vesting_cliff,
+                vault.vesting_duration,
+                now,
+            )?;
+        }
+
+        emit!(ValPurchased {
+            user: self.user.key(),
+            vault: vault_key,
+            sol_amount: sol_lamports,
+            val_amount,
+            total_minted: self.vault.total_minted,
+            rounding_remainder,
+            effective_val_per_sol: pricing::effective_val_per_sol(sol_lamports, val_amount),
+            vested,
+        });
+
+        Ok(())
+    }
 }
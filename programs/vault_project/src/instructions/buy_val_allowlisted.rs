@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::instructions::buy_val::BuyVal;
+use crate::merkle;
+use crate::pricing;
+
+pub fn handler(
+    ctx: Context<BuyVal>,
+    sol_lamports: u64,
+    max_allocation: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Switch to a scheduled rate once it has taken effect
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.apply_scheduled_rate(now)?;
+    let vault = &ctx.accounts.vault;
+
+    // Validations
+    require!(sol_lamports > 0, VaultError::InvalidAmount);
+    vault.require_sale_open(now)?;
+    require!(vault.allowlist_enabled(), VaultError::InvalidAllowlistProof);
+
+    require!(
+        proof.len() <= merkle::MAX_PROOF_LEN,
+        VaultError::InvalidAllowlistProof
+    );
+    let leaf = merkle::allowlist_leaf(&ctx.accounts.user.key(), max_allocation);
+    require!(
+        merkle::verify_proof(&proof, &vault.allowlist_root, leaf),
+        VaultError::InvalidAllowlistProof
+    );
+
+    let oracle_price = ctx.accounts.oracle_price()?;
+    let quote = pricing::quote_val_for_sol(vault, sol_lamports, oracle_price.as_ref())?;
+
+    // Enforce the wallet's allocation across all of its purchases
+    let purchased = ctx
+        .accounts
+        .purchase_record
+        .val_purchased
+        .checked_add(quote.amount)
+        .ok_or(VaultError::MathOverflow)?;
+    require!(purchased <= max_allocation, VaultError::ExceedsAllocation);
+
+    ctx.accounts
+        .settle(sol_lamports, quote.amount, quote.remainder, &ctx.bumps)
+}
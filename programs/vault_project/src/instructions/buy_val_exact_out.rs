@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::instructions::buy_val::BuyVal;
+use crate::pricing;
+
+pub fn handler(ctx: Context<BuyVal>, val_amount: u64, max_sol_in: u64) -> Result<()> {
+    // Switch to a scheduled rate once it has taken effect
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.apply_scheduled_rate(now)?;
+    let vault = &ctx.accounts.vault;
+
+    // Validations
+    require!(val_amount > 0, VaultError::InvalidAmount);
+    vault.require_sale_open(now)?;
+    require!(!vault.allowlist_enabled(), VaultError::AllowlistRequired);
+
+    // Calculate SOL cost from the vault's pricing curve, always rounding up so
+    // the vault is never paid less than the quoted price.
+    let oracle_price = ctx.accounts.oracle_price()?;
+    let quote = pricing::quote_sol_for_val(vault, val_amount, oracle_price.as_ref())?;
+
+    require!(quote.amount <= max_sol_in, VaultError::SlippageExceeded);
+
+    ctx.accounts
+        .settle(quote.amount, val_amount, quote.remainder, &ctx.bumps)
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::VaultError;
+use crate::events::ValPurchasedWithToken;
+use crate::instructions::admin_mint_val::mint_val;
+use crate::pricing;
+use crate::state::{
+    Attestation, PaymentMint, PurchaseRecord, Vault, ATTESTATION_SEED, MINT_AUTHORITY_SEED,
+    PAYMENT_MINT_SEED, PURCHASE_RECORD_SEED, VAL_MINT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct BuyValWithToken<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
+        bump = vault.vault_bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: Treasury PDA that owns the token treasury. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [PAYMENT_MINT_SEED, vault.key().as_ref(), payment_token_mint.key().as_ref()],
+        bump = payment_mint.bump,
+        constraint = payment_mint.enabled @ VaultError::PaymentMintDisabled,
+    )]
+    pub payment_mint: Account<'info, PaymentMint>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint,
+        token::authority = user,
+        token::token_program = payment_token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub treasury_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = val_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_val_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PurchaseRecord::LEN,
+        seeds = [PURCHASE_RECORD_SEED, vault.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    /// Attestation for `user`, required while the vault has an attestor.
+    #[account(
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), user.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Token program of the VAL mint.
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program of the payment mint.
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfers `amount` tokens into `to` and returns what it actually received,
+/// which is less than `amount` when a Token-2022 mint withholds a transfer fee.
+pub(crate) fn transfer_in<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &mut InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let balance_before = to.amount;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority,
+            },
+        ),
+        amount,
+        mint.decimals,
+    )?;
+    to.reload()?;
+
+    let received = to
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(VaultError::MathOverflow)?;
+    require!(received > 0, VaultError::InvalidAmount);
+    Ok(received)
+}
+
+/// Buys VAL with `token_amount` of a whitelisted payment mint at that mint's
+/// rate. The tokens go to the treasury's token account, and VAL is priced on
+/// the amount it receives after any transfer fee.
+pub fn handler(ctx: Context<BuyValWithToken>, token_amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // Validations
+    require!(token_amount > 0, VaultError::InvalidAmount);
+    let now = Clock::get()?.unix_timestamp;
+    let vault = &accounts.vault;
+    vault.require_not_finalized()?;
+    vault.require_sale_open(now)?;
+    vault.require_attestation(&accounts.user.key(), accounts.attestation.as_deref(), now)?;
+    require!(!vault.allowlist_enabled(), VaultError::AllowlistRequired);
+    require!(!vault.vesting_enabled(), VaultError::VestingNotSupported);
+
+    // Transfer payment tokens from user to the treasury's token account
+    let received = transfer_in(
+        &accounts.payment_token_program,
+        &accounts.user_token_account,
+        &accounts.payment_token_mint,
+        &mut accounts.treasury_token_ata,
+        accounts.user.to_account_info(),
+        token_amount,
+    )?;
+
+    let vault = &accounts.vault;
+    let payment_mint = &accounts.payment_mint;
+    let quote = pricing::val_for_token(
+        received,
+        payment_mint.val_per_token_numerator,
+        payment_mint.val_per_token_denominator,
+        accounts.payment_token_mint.decimals,
+        vault.val_decimals,
+        vault.rounding_mode,
+    )?;
+    let val_amount = quote.amount;
+    require!(val_amount > 0, VaultError::ZeroValOutput);
+
+    // Check the per-wallet limit and max supply, keeping reserved supply for admin mints
+    let wallet_val_purchased = accounts
+        .purchase_record
+        .val_purchased
+        .checked_add(val_amount)
+        .ok_or(VaultError::MathOverflow)?;
+    require!(
+        vault.max_val_per_wallet == 0 || wallet_val_purchased <= vault.max_val_per_wallet,
+        VaultError::ExceedsWalletLimit
+    );
+    accounts.vault.record_sale_mint(val_amount)?;
+
+    mint_val(
+        &accounts.vault,
+        &accounts.token_program,
+        &accounts.val_mint,
+        &accounts.mint_authority,
+        ctx.bumps.mint_authority,
+        accounts.user_val_ata.to_account_info(),
+        val_amount,
+    )?;
+
+    accounts.payment_mint.total_received = accounts
+        .payment_mint
+        .total_received
+        .checked_add(received)
+        .ok_or(VaultError::MathOverflow)?;
+
+    // If this purchase record was just initialized, set its fields
+    let record = &mut accounts.purchase_record;
+    if record.vault == Pubkey::default() {
+        record.vault = accounts.vault.key();
+        record.user = accounts.user.key();
+        record.val_purchased = 0;
+        record.sol_spent = 0;
+        record.bump = ctx.bumps.purchase_record;
+    }
+    record.val_purchased = wallet_val_purchased;
+
+    emit!(ValPurchasedWithToken {
+        user: accounts.user.key(),
+        vault: accounts.vault.key(),
+        mint: accounts.payment_token_mint.key(),
+        token_amount,
+        val_amount,
+        rounding_remainder: quote.remainder,
+        total_minted: accounts.vault.total_minted,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::instructions::buy_val::*;
+use crate::state::WSOL_TEMP_SEED;
+
+#[derive(Accounts)]
+pub struct BuyValWsol<'info> {
+    pub buy: BuyVal<'info>,
+
+    #[account(
+        mut,
+        token::mint = native_mint,
+        token::authority = buy.user,
+        token::token_program = wsol_token_program,
+    )]
+    pub user_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    /// Temporary wSOL account, closed to the buyer within the instruction.
+    #[account(
+        init,
+        payer = buy.user,
+        seeds = [WSOL_TEMP_SEED, buy.vault.key().as_ref(), buy.user.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = wsol_temp,
+        token::token_program = wsol_token_program,
+    )]
+    pub wsol_temp: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = native_mint::ID)]
+    pub native_mint: Box<Account<'info, Mint>>,
+
+    pub wsol_token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Unwraps `sol_lamports` of the buyer's wSOL, then buys exactly like `buy_val`.
+pub fn handler(ctx: Context<BuyValWsol>, sol_lamports: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    unwrap_wsol(
+        &accounts.wsol_token_program,
+        &accounts.native_mint,
+        &accounts.user_wsol_account,
+        &accounts.wsol_temp,
+        accounts.buy.user.to_account_info(),
+        &[
+            WSOL_TEMP_SEED,
+            accounts.buy.vault.key().as_ref(),
+            accounts.buy.user.key().as_ref(),
+            &[ctx.bumps.wsol_temp],
+        ],
+        sol_lamports,
+    )?;
+
+    accounts.buy.buy(sol_lamports, &ctx.bumps.buy)
+}
+
+/// Moves `amount` wSOL from `owner`'s token account into the temporary account
+/// and closes it back to `owner`, who receives the lamports plus the rent.
+pub(crate) fn unwrap_wsol<'info>(
+    token_program: &Program<'info, Token>,
+    native_mint: &Account<'info, Mint>,
+    from: &Account<'info, TokenAccount>,
+    wsol_temp: &Account<'info, TokenAccount>,
+    owner: AccountInfo<'info>,
+    wsol_temp_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    token::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: native_mint.to_account_info(),
+                to: wsol_temp.to_account_info(),
+                authority: owner.clone(),
+            },
+        ),
+        amount,
+        native_mint.decimals,
+    )?;
+
+    // PDA-signed close: the temporary account is its own authority
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: wsol_temp.to_account_info(),
+            destination: owner,
+            authority: wsol_temp.to_account_info(),
+        },
+        &[wsol_temp_seeds],
+    ))
+}
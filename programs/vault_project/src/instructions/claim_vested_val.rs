@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::VaultError;
+use crate::events::VestedValClaimed;
+use crate::state::{
+    Vault, VestingPosition, MINT_AUTHORITY_SEED, VAL_MINT_SEED, VESTING_ESCROW_SEED, VESTING_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ClaimVestedVal<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
+        bump = vault.vault_bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_ESCROW_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub vesting_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEED, vault.key().as_ref(), beneficiary.key().as_ref()],
+        bump = vesting_position.bump,
+        constraint = vesting_position.beneficiary == beneficiary.key() @ VaultError::Unauthorized,
+    )]
+    pub vesting_position: Account<'info, VestingPosition>,
+
+    #[account(
+        mut,
+        associated_token::mint = val_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_val_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<ClaimVestedVal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let amount = ctx.accounts.vesting_position.claimable_amount(now)?;
+    require!(amount > 0, VaultError::NothingToClaim);
+
+    // PDA-signed transfer: escrow → beneficiary, signed by the escrow's authority
+    let vault_key = ctx.accounts.vault.key();
+    let mint_authority_seeds: &[&[u8]] = &[
+        MINT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vesting_escrow.to_account_info(),
+                mint: ctx.accounts.val_mint.to_account_info(),
+                to: ctx.accounts.beneficiary_val_ata.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        amount,
+        ctx.accounts.val_mint.decimals,
+    )?;
+
+    let position = &mut ctx.accounts.vesting_position;
+    position.claimed_amount = position
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(VaultError::MathOverflow)?;
+
+    emit!(VestedValClaimed {
+        beneficiary: ctx.accounts.beneficiary.key(),
+        vault: vault_key,
+        position: position.key(),
+        amount,
+        total_claimed: position.claimed_amount,
+    });
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_lang::system_program;
 
 use crate::errors::VaultError;
 use crate::events::DepositMade;
-use crate::state::{ChildAccount, Vault, CHILD_SEED};
+use crate::state::{Attestation, ChildAccount, Vault, ATTESTATION_SEED, CHILD_SEED};
 
 #[derive(Accounts)]
 pub struct DepositAndAutoRegister<'info> {
@@ -34,31 +34,72 @@ pub struct DepositAndAutoRegister<'info> {
     )]
     pub treasury: SystemAccount<'info>,
 
+    /// Attestation for `depositor`, required while the vault has an attestor.
+    #[account(
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), depositor.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<DepositAndAutoRegister>, amount: u64) -> Result<()> {
-    require!(amount > 0, VaultError::InvalidAmount);
+    ctx.accounts.deposit(amount, 0, &ctx.bumps)
+}
 
-    let child = &mut ctx.accounts.child;
+impl<'info> DepositAndAutoRegister<'info> {
+    /// Moves `amount` lamports from the depositor's wallet into the treasury
+    /// and credits the depositor's child account, registering it if needed.
+    /// The deposit is locked for `lock_duration` seconds, or the vault minimum.
+    pub(crate) fn deposit(
+        &mut self,
+        amount: u64,
+        lock_duration: i64,
+        bumps: &DepositAndAutoRegisterBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        self.vault
+            .require_attestation(&self.depositor.key(), self.attestation.as_deref(), now)?;
+        self.child
+            .register(self.vault.key(), self.depositor.key(), now, bumps.child);
 
-    // If this child was just initialized, set its fields
-    if child.vault == Pubkey::default() {
-        child.vault = ctx.accounts.vault.key();
-        child.authority = ctx.accounts.depositor.key();
-        child.total_deposited = 0;
-        child.total_paid_out = 0;
-        child.created_at = Clock::get()?.unix_timestamp;
-        child.bump = ctx.bumps.child;
+        deposit_sol(
+            &mut self.vault,
+            &mut self.child,
+            &self.depositor,
+            &self.treasury,
+            &self.system_program,
+            amount,
+            lock_duration,
+        )
     }
+}
+
+/// Moves `amount` lamports from `payer` into the treasury and credits the
+/// registered `child`, locked for `lock_duration` seconds or the vault minimum.
+pub(crate) fn deposit_sol<'info>(
+    vault: &mut Account<'info, Vault>,
+    child: &mut Account<'info, ChildAccount>,
+    payer: &Signer<'info>,
+    treasury: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+    child.require_not_frozen()?;
 
-    // Transfer SOL from depositor to treasury PDA
+    let now = Clock::get()?.unix_timestamp;
+    let locked_until = vault.deposit_locked_until(lock_duration, now)?;
+
+    // Transfer SOL from payer to treasury PDA
     system_program::transfer(
         CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
+            system_program.to_account_info(),
             system_program::Transfer {
-                from: ctx.accounts.depositor.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
+                from: payer.to_account_info(),
+                to: treasury.to_account_info(),
             },
         ),
         amount,
@@ -68,13 +109,19 @@ pub fn handler(ctx: Context<DepositAndAutoRegister>, amount: u64) -> Result<()>
         .total_deposited
         .checked_add(amount)
         .ok_or(VaultError::MathOverflow)?;
+    vault.record_deposit(amount, child.total_deposited)?;
+    if locked_until > 0 {
+        child.add_lock(amount, locked_until, now)?;
+    }
 
     emit!(DepositMade {
-        depositor: ctx.accounts.depositor.key(),
-        vault: ctx.accounts.vault.key(),
+        depositor: payer.key(),
+        vault: vault.key(),
         child: child.key(),
         amount,
         child_total_deposited: child.total_deposited,
+        beneficiary: child.authority,
+        locked_until,
     });
 
     Ok(())
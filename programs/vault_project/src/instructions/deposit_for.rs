@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::deposit_and_auto_register::deposit_sol;
+use crate::state::{Attestation, ChildAccount, Vault, ATTESTATION_SEED, CHILD_SEED};
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey)]
+pub struct DepositFor<'info> {
+    /// Funds the deposit and the child account rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
+        bump = vault.vault_bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ChildAccount::LEN,
+        seeds = [CHILD_SEED, vault.key().as_ref(), beneficiary.as_ref()],
+        bump,
+    )]
+    pub child: Account<'info, ChildAccount>,
+
+    /// CHECK: Treasury PDA that holds SOL. Validated by seeds derivation.
+    #[account(
+        mut,
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Attestation for the beneficiary, required while the vault has an attestor.
+    #[account(
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), beneficiary.as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Attestation for the payer, also required while the vault has an attestor.
+    #[account(
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), payer.key().as_ref()],
+        bump = payer_attestation.bump,
+    )]
+    pub payer_attestation: Option<Account<'info, Attestation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositFor>, beneficiary: Pubkey, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let now = Clock::get()?.unix_timestamp;
+    accounts
+        .vault
+        .require_attestation(&beneficiary, accounts.attestation.as_deref(), now)?;
+    accounts.vault.require_attestation(
+        &accounts.payer.key(),
+        accounts.payer_attestation.as_deref(),
+        now,
+    )?;
+
+    // A child created here belongs to the beneficiary
+    accounts
+        .child
+        .register(accounts.vault.key(), beneficiary, now, ctx.bumps.child);
+
+    deposit_sol(
+        &mut accounts.vault,
+        &mut accounts.child,
+        &accounts.payer,
+        &accounts.treasury,
+        &accounts.system_program,
+        amount,
+        0,
+    )
+}
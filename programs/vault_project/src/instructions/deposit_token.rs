@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::TokenDepositMade;
+use crate::instructions::buy_val_with_token::transfer_in;
+use crate::state::{
+    Attestation, ChildAccount, ChildTokenBalance, DepositLocks, DepositMint, Vault,
+    ATTESTATION_SEED, CHILD_SEED, CHILD_TOKEN_SEED, DEPOSIT_MINT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
+        bump = vault.vault_bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ChildAccount::LEN,
+        seeds = [CHILD_SEED, vault.key().as_ref(), depositor.key().as_ref()],
+        bump,
+    )]
+    pub child: Account<'info, ChildAccount>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [DEPOSIT_MINT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump = deposit_mint.bump,
+        constraint = deposit_mint.enabled @ VaultError::DepositMintDisabled,
+    )]
+    pub deposit_mint: Account<'info, DepositMint>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ChildTokenBalance::LEN,
+        seeds = [CHILD_TOKEN_SEED, child.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub child_token_balance: Account<'info, ChildTokenBalance>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Treasury PDA that owns the token treasury. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Attestation for `depositor`, required while the vault has an attestor.
+    #[account(
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), depositor.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositToken>, amount: u64, lock_duration: i64) -> Result<()> {
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.vault.require_attestation(
+        &ctx.accounts.depositor.key(),
+        ctx.accounts.attestation.as_deref(),
+        now,
+    )?;
+    let child = &mut ctx.accounts.child;
+    child.register(ctx.accounts.vault.key(), ctx.accounts.depositor.key(), now, ctx.bumps.child);
+    child.require_not_frozen()?;
+
+    let locked_until = ctx.accounts.vault.deposit_locked_until(lock_duration, now)?;
+    let balance = &mut ctx.accounts.child_token_balance;
+
+    // If this balance was just initialized, set its fields
+    if balance.vault == Pubkey::default() {
+        balance.vault = ctx.accounts.vault.key();
+        balance.child = child.key();
+        balance.mint = ctx.accounts.mint.key();
+        balance.total_deposited = 0;
+        balance.total_paid_out = 0;
+        balance.bump = ctx.bumps.child_token_balance;
+        balance.locks = DepositLocks::default();
+    }
+
+    // Transfer tokens from depositor to the treasury's token account, crediting
+    // what arrives after any transfer fee
+    let received = transfer_in(
+        &ctx.accounts.token_program,
+        &ctx.accounts.depositor_token_account,
+        &ctx.accounts.mint,
+        &mut ctx.accounts.treasury_token_ata,
+        ctx.accounts.depositor.to_account_info(),
+        amount,
+    )?;
+
+    balance.total_deposited = balance
+        .total_deposited
+        .checked_add(received)
+        .ok_or(VaultError::MathOverflow)?;
+    if locked_until > 0 {
+        balance.add_lock(received, locked_until, now)?;
+    }
+
+    emit!(TokenDepositMade {
+        depositor: ctx.accounts.depositor.key(),
+        vault: ctx.accounts.vault.key(),
+        child: child.key(),
+        mint: balance.mint,
+        amount: received,
+        child_total_deposited: balance.total_deposited,
+        locked_until,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::deposit_and_auto_register::*;
+
+/// Deposits like `deposit_and_auto_register`, locking the deposit for
+/// `lock_duration` seconds (or the vault minimum, if longer).
+pub fn handler(ctx: Context<DepositAndAutoRegister>, amount: u64, lock_duration: i64) -> Result<()> {
+    ctx.accounts.deposit(amount, lock_duration, &ctx.bumps)
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::instructions::buy_val_wsol::unwrap_wsol;
+use crate::instructions::deposit_and_auto_register::*;
+use crate::state::WSOL_TEMP_SEED;
+
+#[derive(Accounts)]
+pub struct DepositWsolAndAutoRegister<'info> {
+    pub deposit: DepositAndAutoRegister<'info>,
+
+    #[account(
+        mut,
+        token::mint = native_mint,
+        token::authority = deposit.depositor,
+        token::token_program = wsol_token_program,
+    )]
+    pub depositor_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    /// Temporary wSOL account, closed to the depositor within the instruction.
+    #[account(
+        init,
+        payer = deposit.depositor,
+        seeds = [WSOL_TEMP_SEED, deposit.vault.key().as_ref(), deposit.depositor.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = wsol_temp,
+        token::token_program = wsol_token_program,
+    )]
+    pub wsol_temp: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = native_mint::ID)]
+    pub native_mint: Box<Account<'info, Mint>>,
+
+    pub wsol_token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Unwraps `amount` of the depositor's wSOL, then deposits exactly like
+/// `deposit_and_auto_register`.
+pub fn handler(ctx: Context<DepositWsolAndAutoRegister>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    unwrap_wsol(
+        &accounts.wsol_token_program,
+        &accounts.native_mint,
+        &accounts.depositor_wsol_account,
+        &accounts.wsol_temp,
+        accounts.deposit.depositor.to_account_info(),
+        &[
+            WSOL_TEMP_SEED,
+            accounts.deposit.vault.key().as_ref(),
+            accounts.deposit.depositor.key().as_ref(),
+            &[ctx.bumps.wsol_temp],
+        ],
+        amount,
+    )?;
+
+    accounts.deposit.deposit(amount, 0, &ctx.bumps.deposit)
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{self, Mint, SetAuthority, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::ValSupplyFinalized;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct FinalizeValSupply<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<FinalizeValSupply>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    vault.require_not_finalized()?;
+    require!(
+        vault.sale_end != 0 && Clock::get()?.unix_timestamp >= vault.sale_end,
+        VaultError::SaleNotEnded
+    );
+
+    // PDA-signed: mint_authority revokes itself, fixing the supply for good
+    let vault_key = vault.key();
+    let mint_authority_seeds: &[&[u8]] = &[
+        MINT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.val_mint.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.supply_finalized = true;
+
+    emit!(ValSupplyFinalized {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_key,
+        mint: ctx.accounts.val_mint.key(),
+        total_minted: vault.total_minted,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::ChildFrozen;
+use crate::state::{ChildAccount, Vault};
+
+#[derive(Accounts)]
+pub struct FreezeChild<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = child.vault == vault.key() @ VaultError::Unauthorized,
+    )]
+    pub child: Account<'info, ChildAccount>,
+}
+
+/// Freezes the child with `reason`, blocking its deposits and payouts.
+pub fn handler(ctx: Context<FreezeChild>, reason: u8) -> Result<()> {
+    let child = &mut ctx.accounts.child;
+    child.frozen = true;
+    child.freeze_reason = reason;
+
+    emit!(ChildFrozen {
+        admin: ctx.accounts.admin.key(),
+        vault: ctx.accounts.vault.key(),
+        child: child.key(),
+        reason,
+    });
+
+    Ok(())
+}
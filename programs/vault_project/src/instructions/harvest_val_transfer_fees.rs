@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    harvest_withheld_tokens_to_mint, withdraw_withheld_tokens_from_mint,
+    HarvestWithheldTokensToMint, Mint, TokenAccount, WithdrawWithheldTokensFromMint,
+};
+
+use crate::errors::VaultError;
+use crate::events::ValTransferFeesHarvested;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct HarvestValTransferFees<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA, the mint's withdraw-withheld authority; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury PDA that owns the fee destination. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = val_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_val_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Harvests withheld fees from the VAL token accounts passed as remaining
+/// accounts into the mint, then withdraws everything withheld on the mint to
+/// the treasury's VAL account.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, HarvestValTransferFees<'info>>) -> Result<()> {
+    if !ctx.remaining_accounts.is_empty() {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.val_mint.to_account_info(),
+                },
+            ),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+    }
+
+    let balance_before = ctx.accounts.treasury_val_ata.amount;
+
+    // PDA-signed withdrawal: mint_authority is the withdraw-withheld authority
+    let vault_key = ctx.accounts.vault.key();
+    let mint_authority_seeds: &[&[u8]] = &[
+        MINT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+
+    withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        WithdrawWithheldTokensFromMint {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.val_mint.to_account_info(),
+            destination: ctx.accounts.treasury_val_ata.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        },
+        &[mint_authority_seeds],
+    ))?;
+
+    ctx.accounts.treasury_val_ata.reload()?;
+    let amount = ctx
+        .accounts
+        .treasury_val_ata
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(VaultError::MathOverflow)?;
+
+    emit!(ValTransferFeesHarvested {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_key,
+        destination: ctx.accounts.treasury_val_ata.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::errors::VaultError;
 use crate::events::VaultInitialized;
-use crate::state::Vault;
+use crate::state::{
+    PriceCurve, PriceTier, RateLimits, RoundingMode, Vault, DEFAULT_VAL_DECIMALS, MAX_PRICE_TIERS,
+};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -48,9 +50,39 @@ pub fn handler(
     vault.val_per_sol_numerator = val_per_sol_numerator;
     vault.val_per_sol_denominator = val_per_sol_denominator;
     vault.max_supply = max_supply;
+    vault.pending_max_supply = 0;
+    vault.pending_max_supply_at = 0;
+    vault.val_decimals = DEFAULT_VAL_DECIMALS;
     vault.total_minted = 0;
+    vault.reserved_supply = 0;
+    vault.reserved_minted = 0;
+    vault.supply_finalized = false;
     vault.total_deposited = 0;
     vault.total_withdrawn = 0;
+    vault.min_deposit = 0;
+    vault.max_deposit_per_child = 0;
+    vault.max_total_deposits = 0;
+    vault.min_lock_duration = 0;
+    vault.rounding_mode = RoundingMode::Floor;
+    vault.price_curve = PriceCurve::Fixed;
+    vault.price_tiers = [PriceTier::default(); MAX_PRICE_TIERS];
+    vault.price_tier_count = 0;
+    vault.sale_start = 0;
+    vault.sale_end = 0;
+    vault.allowlist_root = [0u8; 32];
+    vault.attestor = Pubkey::default();
+    vault.max_val_per_wallet = 0;
+    vault.max_sol_per_tx = 0;
+    vault.vesting_start = 0;
+    vault.vesting_cliff = 0;
+    vault.vesting_duration = 0;
+    vault.rate_limits = RateLimits::default();
+    vault.pending_rate_limits = RateLimits::default();
+    vault.pending_rate_limits_at = 0;
+    vault.last_rate_update = 0;
+    vault.scheduled_numerator = 0;
+    vault.scheduled_denominator = 0;
+    vault.scheduled_rate_at = 0;
     vault.created_at = clock.unix_timestamp;
     vault.vault_bump = ctx.bumps.vault;
     vault.treasury_bump = ctx.bumps.treasury;
@@ -1,16 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 
 use crate::errors::VaultError;
 use crate::events::ValMintInitialized;
 use crate::state::{Vault, VAL_MINT_SEED, MINT_AUTHORITY_SEED};
 
 #[derive(Accounts)]
+#[instruction(decimals: u8)]
 pub struct InitializeValMint<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
         bump = vault.vault_bump,
         constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
@@ -22,10 +24,11 @@ pub struct InitializeValMint<'info> {
         payer = admin,
         seeds = [VAL_MINT_SEED, vault.key().as_ref()],
         bump,
-        mint::decimals = 9,
+        mint::decimals = decimals,
         mint::authority = mint_authority,
+        mint::token_program = token_program,
     )]
-    pub val_mint: Account<'info, Mint>,
+    pub val_mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
     #[account(
@@ -34,15 +37,19 @@ pub struct InitializeValMint<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializeValMint>) -> Result<()> {
+pub fn handler(ctx: Context<InitializeValMint>, decimals: u8) -> Result<()> {
+    ctx.accounts.vault.set_val_decimals(decimals)?;
+
     emit!(ValMintInitialized {
         admin: ctx.accounts.admin.key(),
         vault: ctx.accounts.vault.key(),
         mint: ctx.accounts.val_mint.key(),
+        token_program: ctx.accounts.token_program.key(),
+        decimals,
     });
 
     Ok(())
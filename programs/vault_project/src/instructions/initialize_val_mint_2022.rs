@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{self, InitializeMint2, Token2022};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_interface::{
+    metadata_pointer_initialize, token_metadata_initialize, transfer_fee_initialize,
+    MetadataPointerInitialize, TokenMetadataInitialize, TransferFeeInitialize,
+};
+
+use crate::errors::VaultError;
+use crate::events::ValMintInitialized;
+use crate::instructions::set_val_metadata::validate_metadata;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+
+/// Maximum transfer fee, in basis points, accepted by Token-2022.
+const MAX_TRANSFER_FEE_BPS: u16 = 10_000;
+
+/// Optional Token-2022 transfer fee for the VAL mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferFeeArgs {
+    /// Fee charged on each transfer, in basis points of the amount.
+    pub transfer_fee_basis_points: u16,
+    /// Upper bound on the fee charged per transfer, in smallest units.
+    pub maximum_fee: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeValMint2022<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// CHECK: VAL mint PDA, created here with its Token-2022 extensions; verified by seeds.
+    #[account(
+        mut,
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub val_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeValMint2022>,
+    decimals: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+    transfer_fee: Option<TransferFeeArgs>,
+) -> Result<()> {
+    ctx.accounts.vault.set_val_decimals(decimals)?;
+    validate_metadata(&name, &symbol, &uri)?;
+    if let Some(fee) = transfer_fee {
+        require!(
+            fee.transfer_fee_basis_points <= MAX_TRANSFER_FEE_BPS,
+            VaultError::InvalidTransferFee
+        );
+    }
+
+    let vault_key = ctx.accounts.vault.key();
+    let mint_key = ctx.accounts.val_mint.key();
+    let mint_authority_key = ctx.accounts.mint_authority.key();
+    let token_program_key = ctx.accounts.token_program.key();
+
+    // Size the mint for its fixed extensions; the metadata TLV is appended by
+    // the token program on initialize, so fund rent for it up front.
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if transfer_fee.is_some() {
+        extensions.push(ExtensionType::TransferFeeConfig);
+    }
+    let mint_len = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(mint_authority_key),
+        mint: mint_key,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+    let metadata_len = metadata.tlv_size_of()?;
+    let lamports = Rent::get()?.minimum_balance(mint_len + metadata_len);
+
+    let val_mint_seeds: &[&[u8]] = &[VAL_MINT_SEED, vault_key.as_ref(), &[ctx.bumps.val_mint]];
+    let mint_authority_seeds: &[&[u8]] = &[
+        MINT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+
+    create_pda_account(
+        &ctx.accounts.system_program,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.val_mint.to_account_info(),
+        val_mint_seeds,
+        lamports,
+        mint_len,
+        &token_program_key,
+    )?;
+
+    // Extensions must be initialized before the mint itself
+    if let Some(fee) = transfer_fee {
+        transfer_fee_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferFeeInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.val_mint.to_account_info(),
+                },
+            ),
+            Some(&mint_authority_key),
+            Some(&mint_authority_key),
+            fee.transfer_fee_basis_points,
+            fee.maximum_fee,
+        )?;
+    }
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.val_mint.to_account_info(),
+            },
+        ),
+        Some(mint_authority_key),
+        Some(mint_key),
+    )?;
+
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.val_mint.to_account_info(),
+            },
+        ),
+        decimals,
+        &mint_authority_key,
+        None,
+    )?;
+
+    // PDA-signed metadata init: mint_authority signs as mint and update authority
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.val_mint.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                mint: ctx.accounts.val_mint.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    emit!(ValMintInitialized {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_key,
+        mint: mint_key,
+        token_program: token_program_key,
+        decimals,
+    });
+
+    Ok(())
+}
+
+/// Creates the PDA `account` owned by `owner`. An address that was already
+/// sent lamports cannot go through `create_account`, so it is topped up to
+/// `lamports`, allocated and assigned instead.
+fn create_pda_account<'info>(
+    system_program: &Program<'info, System>,
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    let shortfall = lamports.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        owner,
+    )
+}
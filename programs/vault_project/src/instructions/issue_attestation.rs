@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::AttestationIssued;
+use crate::state::{Attestation, Vault, ATTESTATION_SEED};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct IssueAttestation<'info> {
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.attestor == attestor.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = Attestation::LEN,
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), user.as_ref()],
+        bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Issues or renews `user`'s attestation, valid until `expires_at`.
+pub fn handler(ctx: Context<IssueAttestation>, user: Pubkey, expires_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, VaultError::InvalidAttestation);
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.vault = ctx.accounts.vault.key();
+    attestation.user = user;
+    attestation.attestor = ctx.accounts.attestor.key();
+    attestation.issued_at = now;
+    attestation.expires_at = expires_at;
+    attestation.bump = ctx.bumps.attestation;
+
+    emit!(AttestationIssued {
+        attestor: ctx.accounts.attestor.key(),
+        vault: ctx.accounts.vault.key(),
+        user,
+        expires_at,
+    });
+
+    Ok(())
+}
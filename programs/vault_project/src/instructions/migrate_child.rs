@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::ChildMigrated;
+use crate::instructions::migrate_vault::grow_account;
+use crate::state::{ChildAccount, Vault};
+
+#[derive(Accounts)]
+pub struct MigrateChild<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Child account in the original layout, which `Account<ChildAccount>`
+    /// cannot load. Validated by owner; discriminator, size and vault are checked
+    /// in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub child: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Extends a child account created with the original layout to
+/// `ChildAccount::LEN`. The appended fields are zeroed: no locks, not frozen.
+pub fn handler(ctx: Context<MigrateChild>) -> Result<()> {
+    let child_info = ctx.accounts.child.to_account_info();
+    {
+        let data = child_info.try_borrow_data()?;
+        require!(
+            data.len() == ChildAccount::LEGACY_LEN && data.starts_with(ChildAccount::DISCRIMINATOR),
+            VaultError::AlreadyMigrated
+        );
+        // The child's vault is the first field after the discriminator.
+        let vault_offset = ChildAccount::DISCRIMINATOR.len();
+        require!(
+            data[vault_offset..vault_offset + 32] == ctx.accounts.vault.key().to_bytes(),
+            VaultError::Unauthorized
+        );
+    }
+
+    grow_account(
+        &child_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program,
+        ChildAccount::LEN,
+    )?;
+
+    emit!(ChildMigrated {
+        admin: ctx.accounts.admin.key(),
+        vault: ctx.accounts.vault.key(),
+        child: child_info.key(),
+        len: ChildAccount::LEN as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::VaultError;
+use crate::events::VaultMigrated;
+use crate::state::{Vault, DEFAULT_VAL_DECIMALS};
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Vault in the original layout, which `Account<Vault>` cannot load.
+    /// Validated by seeds and owner; discriminator and size are checked in the handler.
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Extends a vault created with the original layout to `Vault::LEN`, setting
+/// the appended fields to the values `initialize` uses.
+pub fn handler(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    {
+        let data = vault_info.try_borrow_data()?;
+        require!(
+            data.len() == Vault::LEGACY_LEN && data.starts_with(Vault::DISCRIMINATOR),
+            VaultError::AlreadyMigrated
+        );
+    }
+
+    grow_account(
+        &vault_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program,
+        Vault::LEN,
+    )?;
+
+    // Appended fields are zeroed, which matches `initialize` except for the
+    // decimals; the original VAL mint always used the default.
+    let mut data = vault_info.try_borrow_mut_data()?;
+    let mut vault = Vault::try_deserialize(&mut &data[..])?;
+    vault.val_decimals = DEFAULT_VAL_DECIMALS;
+    vault.try_serialize(&mut &mut data[..])?;
+
+    emit!(VaultMigrated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_info.key(),
+        len: Vault::LEN as u64,
+    });
+
+    Ok(())
+}
+
+/// Tops `account` up to rent exemption at `new_len` from `payer` and resizes
+/// it, zero-filling the new bytes.
+pub(crate) fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
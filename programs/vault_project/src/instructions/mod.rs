@@ -1,17 +1,97 @@
 pub mod initialize;
 pub mod deposit_and_auto_register;
+pub mod deposit_for;
 pub mod admin_request_payout;
 pub mod admin_execute_payout;
 pub mod admin_withdraw;
 pub mod initialize_val_mint;
+pub mod initialize_val_mint_2022;
+pub mod harvest_val_transfer_fees;
+pub mod set_val_metadata;
 pub mod buy_val;
+pub mod buy_val_exact_out;
+pub mod buy_val_allowlisted;
 pub mod update_exchange_rate;
+pub mod schedule_rate_change;
+pub mod update_rounding_mode;
+pub mod update_price_curve;
+pub mod set_price_tiers;
+pub mod set_sale_window;
+pub mod set_allowlist_root;
+pub mod set_purchase_limits;
+pub mod set_vesting_schedule;
+pub mod claim_vested_val;
+pub mod set_reserved_supply;
+pub mod admin_mint_val;
+pub mod admin_mint_val_batch;
+pub mod finalize_val_supply;
+pub mod update_max_supply;
+pub mod apply_max_supply_increase;
+pub mod propose_rate_limits;
+pub mod apply_rate_limits;
+pub mod set_payment_mint;
+pub mod buy_val_with_token;
+pub mod admin_withdraw_token;
+pub mod set_deposit_mint;
+pub mod deposit_token;
+pub mod admin_request_token_payout;
+pub mod admin_execute_token_payout;
+pub mod buy_val_wsol;
+pub mod set_deposit_limits;
+pub mod deposit_wsol_and_auto_register;
+pub mod deposit_with_lock;
+pub mod set_min_lock_duration;
+pub mod set_attestor;
+pub mod issue_attestation;
+pub mod revoke_attestation;
+pub mod freeze_child;
+pub mod unfreeze_child;
+pub mod migrate_vault;
+pub mod migrate_child;
 
 pub use initialize::*;
 pub use deposit_and_auto_register::*;
+pub use deposit_for::*;
 pub use admin_request_payout::*;
 pub use admin_execute_payout::*;
 pub use admin_withdraw::*;
 pub use initialize_val_mint::*;
+pub use initialize_val_mint_2022::*;
+pub use harvest_val_transfer_fees::*;
+pub use set_val_metadata::*;
 pub use buy_val::*;
 pub use update_exchange_rate::*;
+pub use schedule_rate_change::*;
+pub use update_rounding_mode::*;
+pub use update_price_curve::*;
+pub use set_price_tiers::*;
+pub use set_sale_window::*;
+pub use set_allowlist_root::*;
+pub use set_purchase_limits::*;
+pub use set_vesting_schedule::*;
+pub use claim_vested_val::*;
+pub use set_reserved_supply::*;
+pub use admin_mint_val::*;
+pub use admin_mint_val_batch::*;
+pub use finalize_val_supply::*;
+pub use update_max_supply::*;
+pub use apply_max_supply_increase::*;
+pub use propose_rate_limits::*;
+pub use apply_rate_limits::*;
+pub use set_payment_mint::*;
+pub use buy_val_with_token::*;
+pub use admin_withdraw_token::*;
+pub use set_deposit_mint::*;
+pub use deposit_token::*;
+pub use admin_request_token_payout::*;
+pub use admin_execute_token_payout::*;
+pub use buy_val_wsol::*;
+pub use set_deposit_limits::*;
+pub use deposit_wsol_and_auto_register::*;
+pub use set_min_lock_duration::*;
+pub use set_attestor::*;
+pub use issue_attestation::*;
+pub use revoke_attestation::*;
+pub use freeze_child::*;
+pub use migrate_vault::*;
+pub use migrate_child::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::RateLimitsProposed;
+use crate::state::{RateLimits, Vault, RATE_LIMITS_DELAY};
+
+#[derive(Accounts)]
+pub struct ProposeRateLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+pub fn handler(ctx: Context<ProposeRateLimits>, limits: RateLimits) -> Result<()> {
+    limits.validate()?;
+
+    let vault = &mut ctx.accounts.vault;
+    let effective_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(RATE_LIMITS_DELAY)
+        .ok_or(VaultError::MathOverflow)?;
+
+    vault.pending_rate_limits = limits;
+    vault.pending_rate_limits_at = effective_at;
+
+    emit!(RateLimitsProposed {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        limits,
+        effective_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::AttestationRevoked;
+use crate::state::{Attestation, Vault, ATTESTATION_SEED};
+
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, vault.admin_authority.as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.attestor == attestor.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        close = attestor,
+        seeds = [ATTESTATION_SEED, vault.key().as_ref(), attestation.user.as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+/// Revokes an attestation by closing it; rent goes to the attestor.
+pub fn handler(ctx: Context<RevokeAttestation>) -> Result<()> {
+    emit!(AttestationRevoked {
+        attestor: ctx.accounts.attestor.key(),
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.attestation.user,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::{RateChangeScheduled, RateUpdated};
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct ScheduleRateChange<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+/// Replaces any previously scheduled change. The rate limits are checked
+/// against the current rate as of `effective_at`.
+pub fn handler(
+    ctx: Context<ScheduleRateChange>,
+    numerator: u64,
+    denominator: u64,
+    effective_at: i64,
+) -> Result<()> {
+    require!(numerator > 0, VaultError::InvalidNumerator);
+    require!(denominator > 0, VaultError::InvalidDenominator);
+
+    let vault = &mut ctx.accounts.vault;
+    let now = Clock::get()?.unix_timestamp;
+    require!(effective_at > now, VaultError::InvalidRateSchedule);
+
+    // A change that is already due takes effect before the new one is queued
+    let old_numerator = vault.val_per_sol_numerator;
+    let old_denominator = vault.val_per_sol_denominator;
+    if vault.apply_scheduled_rate(now) {
+        emit!(RateUpdated {
+            admin: ctx.accounts.admin.key(),
+            vault: vault.key(),
+            old_numerator,
+            old_denominator,
+            new_numerator: vault.val_per_sol_numerator,
+            new_denominator: vault.val_per_sol_denominator,
+        });
+    }
+    vault.check_rate_update(numerator, denominator, effective_at)?;
+
+    vault.scheduled_numerator = numerator;
+    vault.scheduled_denominator = denominator;
+    vault.scheduled_rate_at = effective_at;
+
+    emit!(RateChangeScheduled {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        new_numerator: numerator,
+        new_denominator: denominator,
+        effective_at,
+    });
+
+    Ok(())
+}
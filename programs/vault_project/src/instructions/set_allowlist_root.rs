@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::AllowlistRootUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<SetAllowlistRoot>, allowlist_root: [u8; 32]) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.allowlist_root = allowlist_root;
+
+    emit!(AllowlistRootUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        allowlist_root,
+    });
+
+    Ok(())
+}
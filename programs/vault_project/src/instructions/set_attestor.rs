@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::AttestorUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetAttestor<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<SetAttestor>, attestor: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.attestor = attestor;
+
+    emit!(AttestorUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        attestor,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::DepositLimitsUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetDepositLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(
+    ctx: Context<SetDepositLimits>,
+    min_deposit: u64,
+    max_deposit_per_child: u64,
+    max_total_deposits: u64,
+) -> Result<()> {
+    require!(
+        max_deposit_per_child == 0 || min_deposit <= max_deposit_per_child,
+        VaultError::InvalidDepositLimits
+    );
+    require!(
+        max_total_deposits == 0 || min_deposit <= max_total_deposits,
+        VaultError::InvalidDepositLimits
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    vault.min_deposit = min_deposit;
+    vault.max_deposit_per_child = max_deposit_per_child;
+    vault.max_total_deposits = max_total_deposits;
+
+    emit!(DepositLimitsUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        min_deposit,
+        max_deposit_per_child,
+        max_total_deposits,
+    });
+
+    Ok(())
+}
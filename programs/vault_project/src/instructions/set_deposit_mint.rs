@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::DepositMintUpdated;
+use crate::state::{DepositMint, Vault, DEPOSIT_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct SetDepositMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = DepositMint::LEN,
+        seeds = [DEPOSIT_MINT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub deposit_mint: Account<'info, DepositMint>,
+
+    /// CHECK: Treasury PDA that owns the token treasury. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetDepositMint>, enabled: bool) -> Result<()> {
+    let deposit_mint = &mut ctx.accounts.deposit_mint;
+
+    // If this deposit mint was just initialized, set its fields
+    if deposit_mint.vault == Pubkey::default() {
+        deposit_mint.vault = ctx.accounts.vault.key();
+        deposit_mint.mint = ctx.accounts.mint.key();
+        deposit_mint.bump = ctx.bumps.deposit_mint;
+    }
+    deposit_mint.enabled = enabled;
+
+    emit!(DepositMintUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: ctx.accounts.vault.key(),
+        mint: deposit_mint.mint,
+        enabled,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::MinLockDurationUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetMinLockDuration<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<SetMinLockDuration>, min_lock_duration: i64) -> Result<()> {
+    require!(min_lock_duration >= 0, VaultError::InvalidLockDuration);
+
+    let vault = &mut ctx.accounts.vault;
+    vault.min_lock_duration = min_lock_duration;
+
+    emit!(MinLockDurationUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        min_lock_duration,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::PaymentMintUpdated;
+use crate::state::{PaymentMint, Vault, PAYMENT_MINT_SEED};
+
+#[derive(Accounts)]
+pub struct SetPaymentMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PaymentMint::LEN,
+        seeds = [PAYMENT_MINT_SEED, vault.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub payment_mint: Account<'info, PaymentMint>,
+
+    /// CHECK: Treasury PDA that owns the token treasury. Validated by seeds derivation.
+    #[account(
+        seeds = [Vault::TREASURY_SEED, vault.key().as_ref()],
+        bump = vault.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetPaymentMint>,
+    val_per_token_numerator: u64,
+    val_per_token_denominator: u64,
+    enabled: bool,
+) -> Result<()> {
+    require!(val_per_token_numerator > 0, VaultError::InvalidNumerator);
+    require!(val_per_token_denominator > 0, VaultError::InvalidDenominator);
+
+    let payment_mint = &mut ctx.accounts.payment_mint;
+
+    // If this payment mint was just initialized, set its fields
+    if payment_mint.vault == Pubkey::default() {
+        payment_mint.vault = ctx.accounts.vault.key();
+        payment_mint.mint = ctx.accounts.mint.key();
+        payment_mint.total_received = 0;
+        payment_mint.bump = ctx.bumps.payment_mint;
+    }
+    payment_mint.val_per_token_numerator = val_per_token_numerator;
+    payment_mint.val_per_token_denominator = val_per_token_denominator;
+    payment_mint.enabled = enabled;
+
+    emit!(PaymentMintUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: ctx.accounts.vault.key(),
+        mint: payment_mint.mint,
+        val_per_token_numerator,
+        val_per_token_denominator,
+        enabled,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::PriceTiersUpdated;
+use crate::state::{PriceTier, Vault, MAX_PRICE_TIERS};
+
+#[derive(Accounts)]
+pub struct SetPriceTiers<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<SetPriceTiers>, tiers: Vec<PriceTier>) -> Result<()> {
+    require!(!tiers.is_empty(), VaultError::InvalidPriceTiers);
+    require!(tiers.len() <= MAX_PRICE_TIERS, VaultError::InvalidPriceTiers);
+
+    let mut previous_cap = 0u64;
+    for tier in &tiers {
+        require!(tier.supply_cap > previous_cap, VaultError::InvalidPriceTiers);
+        require!(tier.val_per_sol_numerator > 0, VaultError::InvalidNumerator);
+        require!(tier.val_per_sol_denominator > 0, VaultError::InvalidDenominator);
+        previous_cap = tier.supply_cap;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.check_price_tiers(&tiers)?;
+    vault.record_pricing_change(Clock::get()?.unix_timestamp)?;
+
+    // A tier is sold (and frozen) once minting has entered its band.
+    let mut tier_start = 0u64;
+    for (index, existing) in vault.active_price_tiers().iter().enumerate() {
+        if tier_start >= vault.total_minted {
+            break;
+        }
+        require!(
+            tiers.get(index) == Some(existing),
+            VaultError::TierAlreadySold
+        );
+        tier_start = existing.supply_cap;
+    }
+
+    let mut table = [PriceTier::default(); MAX_PRICE_TIERS];
+    table[..tiers.len()].copy_from_slice(&tiers);
+    vault.price_tiers = table;
+    vault.price_tier_count = tiers.len() as u8;
+
+    emit!(PriceTiersUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        tiers,
+    });
+
+    Ok(())
+}
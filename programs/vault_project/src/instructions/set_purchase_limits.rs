@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::PurchaseLimitsUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetPurchaseLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(
+    ctx: Context<SetPurchaseLimits>,
+    max_val_per_wallet: u64,
+    max_sol_per_tx: u64,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.max_val_per_wallet = max_val_per_wallet;
+    vault.max_sol_per_tx = max_sol_per_tx;
+
+    emit!(PurchaseLimitsUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        max_val_per_wallet,
+        max_sol_per_tx,
+    });
+
+    Ok(())
+}
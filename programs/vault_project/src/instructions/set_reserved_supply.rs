@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::ReservedSupplyUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetReservedSupply<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<SetReservedSupply>, reserved_supply: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.set_reserved_supply(reserved_supply)?;
+
+    emit!(ReservedSupplyUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        reserved_supply,
+    });
+
+    Ok(())
+}
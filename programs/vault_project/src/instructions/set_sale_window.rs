@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::SaleWindowUpdated;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct SetSaleWindow<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<SetSaleWindow>, sale_start: i64, sale_end: i64) -> Result<()> {
+    require!(sale_start >= 0 && sale_end >= 0, VaultError::InvalidSaleWindow);
+    require!(
+        sale_start == 0 || sale_end == 0 || sale_start < sale_end,
+        VaultError::InvalidSaleWindow
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    vault.sale_start = sale_start;
+    vault.sale_end = sale_end;
+
+    emit!(SaleWindowUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        sale_start,
+        sale_end,
+    });
+
+    Ok(())
+}
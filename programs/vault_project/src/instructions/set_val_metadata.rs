@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::{self, CreateMetadataAccountsV3, Metadata, UpdateMetadataAccountsV2};
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::ValMetadataUpdated;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED};
+
+/// Metaplex Token Metadata field limits.
+const MAX_NAME_LENGTH: usize = 32;
+const MAX_SYMBOL_LENGTH: usize = 10;
+const MAX_URI_LENGTH: usize = 200;
+
+#[derive(Accounts)]
+pub struct SetValMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Signs as mint and update authority; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata PDA of the VAL mint, created or updated by the metadata program.
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), val_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Rejects a VAL name, symbol or URI outside the Metaplex field limits.
+pub(crate) fn validate_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= MAX_NAME_LENGTH,
+        VaultError::InvalidMetadata
+    );
+    require!(
+        !symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LENGTH,
+        VaultError::InvalidMetadata
+    );
+    require!(uri.len() <= MAX_URI_LENGTH, VaultError::InvalidMetadata);
+    Ok(())
+}
+
+pub fn handler(ctx: Context<SetValMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+    validate_metadata(&name, &symbol, &uri)?;
+
+    let data = DataV2 {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    // PDA-signed CPI: mint_authority is both the mint and metadata update authority
+    let vault_key = ctx.accounts.vault.key();
+    let mint_authority_seeds: &[&[u8]] = &[
+        MINT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+
+    if ctx.accounts.metadata.data_is_empty() {
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    mint: ctx.accounts.val_mint.to_account_info(),
+                    mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                    payer: ctx.accounts.admin.to_account_info(),
+                    update_authority: ctx.accounts.mint_authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                &[mint_authority_seeds],
+            ),
+            data,
+            true,
+            true,
+            None,
+        )?;
+    } else {
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    update_authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                &[mint_authority_seeds],
+            ),
+            None,
+            Some(data),
+            None,
+            None,
+        )?;
+    }
+
+    emit!(ValMetadataUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault_key,
+        mint: ctx.accounts.val_mint.key(),
+        name,
+        symbol,
+        uri,
+    });
+
+    Ok(())
+}
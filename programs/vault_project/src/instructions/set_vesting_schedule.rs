@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::VaultError;
+use crate::events::VestingScheduleUpdated;
+use crate::state::{Vault, MINT_AUTHORITY_SEED, VAL_MINT_SEED, VESTING_ESCROW_SEED};
+
+#[derive(Accounts)]
+pub struct SetVestingSchedule<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [VAL_MINT_SEED, vault.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub val_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Mint authority PDA. Does not hold data; verified by seeds.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Program-owned escrow holding VAL until it vests.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [VESTING_ESCROW_SEED, vault.key().as_ref()],
+        bump,
+        token::mint = val_mint,
+        token::authority = mint_authority,
+        token::token_program = token_program,
+    )]
+    pub vesting_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetVestingSchedule>,
+    vesting_start: i64,
+    vesting_cliff: i64,
+    vesting_duration: i64,
+) -> Result<()> {
+    require!(vesting_start >= 0, VaultError::InvalidVestingSchedule);
+    require!(vesting_cliff >= 0, VaultError::InvalidVestingSchedule);
+    require!(
+        vesting_cliff <= vesting_duration,
+        VaultError::InvalidVestingSchedule
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    vault.vesting_start = vesting_start;
+    vault.vesting_cliff = vesting_cliff;
+    vault.vesting_duration = vesting_duration;
+
+    emit!(VestingScheduleUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        vesting_start,
+        vesting_cliff,
+        vesting_duration,
+    });
+
+    Ok(())
+}
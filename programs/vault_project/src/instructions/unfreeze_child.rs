@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ChildUnfrozen;
+use crate::instructions::freeze_child::FreezeChild;
+
+/// Lifts a freeze, clearing its reason code.
+pub fn handler(ctx: Context<FreezeChild>) -> Result<()> {
+    let child = &mut ctx.accounts.child;
+    child.frozen = false;
+    child.freeze_reason = 0;
+
+    emit!(ChildUnfrozen {
+        admin: ctx.accounts.admin.key(),
+        vault: ctx.accounts.vault.key(),
+        child: child.key(),
+    });
+
+    Ok(())
+}
@@ -26,12 +26,16 @@ pub fn handler(
     require!(new_denominator > 0, VaultError::InvalidDenominator);
 
     let vault = &mut ctx.accounts.vault;
+    let now = Clock::get()?.unix_timestamp;
+    vault.check_rate_update(new_numerator, new_denominator, now)?;
 
     let old_numerator = vault.val_per_sol_numerator;
     let old_denominator = vault.val_per_sol_denominator;
 
     vault.val_per_sol_numerator = new_numerator;
     vault.val_per_sol_denominator = new_denominator;
+    vault.last_rate_update = now;
+    vault.clear_scheduled_rate();
 
     emit!(RateUpdated {
         admin: ctx.accounts.admin.key(),
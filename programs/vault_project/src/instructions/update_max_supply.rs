@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::{MaxSupplyIncreaseQueued, MaxSupplyUpdated, ReservedSupplyUpdated};
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct UpdateMaxSupply<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+/// Decreases apply immediately; increases are queued behind the timelock and
+/// applied with `apply_max_supply_increase`.
+pub fn handler(ctx: Context<UpdateMaxSupply>, new_max_supply: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let old_max_supply = vault.max_supply;
+
+    if new_max_supply <= old_max_supply {
+        let reserve_clamped = vault.decrease_max_supply(new_max_supply)?;
+
+        emit!(MaxSupplyUpdated {
+            admin: ctx.accounts.admin.key(),
+            vault: vault.key(),
+            old_max_supply,
+            new_max_supply,
+        });
+        if reserve_clamped {
+            emit!(ReservedSupplyUpdated {
+                admin: ctx.accounts.admin.key(),
+                vault: vault.key(),
+                reserved_supply: vault.reserved_supply,
+            });
+        }
+    } else {
+        vault.queue_max_supply_increase(new_max_supply, Clock::get()?.unix_timestamp)?;
+
+        emit!(MaxSupplyIncreaseQueued {
+            admin: ctx.accounts.admin.key(),
+            vault: vault.key(),
+            pending_max_supply: new_max_supply,
+            effective_at: vault.pending_max_supply_at,
+        });
+    }
+
+    Ok(())
+}
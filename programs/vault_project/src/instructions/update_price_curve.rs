@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::PriceCurveUpdated;
+use crate::state::{PriceCurve, Vault};
+
+#[derive(Accounts)]
+pub struct UpdatePriceCurve<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<UpdatePriceCurve>, price_curve: PriceCurve) -> Result<()> {
+    match price_curve {
+        PriceCurve::Fixed => {}
+        PriceCurve::Linear { start_price, step, .. }
+        | PriceCurve::Exponential { start_price, step, .. } => {
+            require!(start_price > 0, VaultError::InvalidPriceCurve);
+            require!(step > 0, VaultError::InvalidPriceCurve);
+        }
+        PriceCurve::Tiered => {
+            require!(
+                ctx.accounts.vault.price_tier_count > 0,
+                VaultError::InvalidPriceCurve
+            );
+        }
+        PriceCurve::Oracle {
+            price_feed,
+            usd_price_per_val,
+            max_price_age,
+            max_confidence_bps,
+        } => {
+            require!(price_feed != Pubkey::default(), VaultError::InvalidPriceFeed);
+            require!(usd_price_per_val > 0, VaultError::InvalidPriceCurve);
+            require!(max_price_age > 0, VaultError::InvalidPriceCurve);
+            require!(max_confidence_bps <= 10_000, VaultError::InvalidPriceCurve);
+        }
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.check_price_curve(&price_curve)?;
+    vault.record_pricing_change(Clock::get()?.unix_timestamp)?;
+
+    let old_curve = vault.price_curve;
+    vault.price_curve = price_curve;
+
+    emit!(PriceCurveUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        old_curve,
+        new_curve: price_curve,
+    });
+
+    Ok(())
+}
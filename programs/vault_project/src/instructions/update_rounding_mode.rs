@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::events::RoundingModeUpdated;
+use crate::state::{RoundingMode, Vault};
+
+#[derive(Accounts)]
+pub struct UpdateRoundingMode<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump = vault.vault_bump,
+        constraint = vault.admin_authority == admin.key() @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<UpdateRoundingMode>, rounding_mode: RoundingMode) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    let old_mode = vault.rounding_mode;
+    vault.rounding_mode = rounding_mode;
+
+    emit!(RoundingModeUpdated {
+        admin: ctx.accounts.admin.key(),
+        vault: vault.key(),
+        old_mode,
+        new_mode: rounding_mode,
+    });
+
+    Ok(())
+}
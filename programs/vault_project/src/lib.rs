@@ -3,9 +3,13 @@ use anchor_lang::prelude::*;
 mod errors;
 mod events;
 mod instructions;
+mod merkle;
+mod oracle;
+mod pricing;
 mod state;
 
 use instructions::*;
+use state::{PriceCurve, PriceTier, RateLimits, RoundingMode};
 
 declare_id!("8ZddStKAumEMQQ8nHViTCxBU7AYnxt8rACHJqWg53vsG");
 
@@ -15,9 +19,9 @@ pub mod vault_project {
 
     /// Creates a new admin-controlled vault with a dedicated treasury PDA.
     /// Parameters:
-    /// - val_per_sol_numerator: Exchange rate numerator (val = sol * num / denom)
+    /// - val_per_sol_numerator: Exchange rate numerator (whole VAL per whole SOL = num / denom)
     /// - val_per_sol_denominator: Exchange rate denominator
-    /// - max_supply: Maximum VAL tokens that can be minted
+    /// - max_supply: Maximum VAL tokens that can be minted, in smallest units
     pub fn initialize(
         ctx: Context<Initialize>,
         val_per_sol_numerator: u64,
@@ -35,6 +39,14 @@ pub mod vault_project {
         instructions::deposit_and_auto_register::handler(ctx, amount)
     }
 
+    /// Deposits SOL on behalf of `beneficiary`. The payer funds the transfer and
+    /// rent; the child account is seeded by and belongs to the beneficiary.
+    /// While the vault has an attestor, both the payer and the beneficiary need
+    /// an attestation.
+    pub fn deposit_for(ctx: Context<DepositFor>, beneficiary: Pubkey, amount: u64) -> Result<()> {
+        instructions::deposit_for::handler(ctx, beneficiary, amount)
+    }
+
     /// Admin requests a payout from a child account's deposited balance.
     pub fn admin_request_payout(
         ctx: Context<AdminRequestPayout>,
@@ -55,19 +67,76 @@ pub mod vault_project {
         instructions::admin_withdraw::handler(ctx, amount)
     }
 
-    /// Initializes the VAL token mint for a vault.
-    pub fn initialize_val_mint(ctx: Context<InitializeValMint>) -> Result<()> {
-        instructions::initialize_val_mint::handler(ctx)
+    /// Initializes the VAL token mint for a vault under the given token program.
+    /// `decimals` is stored on the vault; `max_supply` must be a whole number of VAL.
+    pub fn initialize_val_mint(ctx: Context<InitializeValMint>, decimals: u8) -> Result<()> {
+        instructions::initialize_val_mint::handler(ctx, decimals)
+    }
+
+    /// Initializes the VAL token mint under Token-2022 with on-mint metadata
+    /// (metadata-pointer + token-metadata) and an optional transfer fee.
+    pub fn initialize_val_mint_2022(
+        ctx: Context<InitializeValMint2022>,
+        decimals: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+        transfer_fee: Option<TransferFeeArgs>,
+    ) -> Result<()> {
+        instructions::initialize_val_mint_2022::handler(ctx, decimals, name, symbol, uri, transfer_fee)
+    }
+
+    /// Admin harvests withheld VAL transfer fees to the treasury's VAL account.
+    /// Token accounts to harvest from are passed as remaining accounts.
+    pub fn harvest_val_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestValTransferFees<'info>>,
+    ) -> Result<()> {
+        instructions::harvest_val_transfer_fees::handler(ctx)
+    }
+
+    /// Creates or updates the Metaplex metadata (name, symbol, URI) of the VAL mint.
+    /// The mint_authority PDA signs as both mint and update authority.
+    pub fn set_val_metadata(
+        ctx: Context<SetValMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::set_val_metadata::handler(ctx, name, symbol, uri)
     }
 
     /// Buy VAL tokens by sending SOL to the vault treasury.
-    /// Calculates: val_amount = sol_lamports * numerator / denominator
+    /// Fixed pricing sells numerator / denominator whole VAL per whole SOL.
+    /// Bonding curves and supply tiers integrate the price over the purchase from `total_minted`.
+    /// Oracle pricing converts a USD price per VAL at the SOL/USD feed price.
+    /// A wallet's first purchase also pays rent for its `PurchaseRecord`.
     pub fn buy_val(ctx: Context<BuyVal>, sol_lamports: u64) -> Result<()> {
         instructions::buy_val::handler(ctx, sol_lamports)
     }
 
+    /// Buy an exact amount of VAL tokens, paying at most `max_sol_in` lamports.
+    /// Fixed pricing charges ceil(val_amount * denominator / numerator), scaled by the VAL and SOL decimals.
+    pub fn buy_val_exact_out(
+        ctx: Context<BuyVal>,
+        val_amount: u64,
+        max_sol_in: u64,
+    ) -> Result<()> {
+        instructions::buy_val_exact_out::handler(ctx, val_amount, max_sol_in)
+    }
+
+    /// Buy VAL tokens during an allowlisted round.
+    /// `proof` shows `(user, max_allocation)` is a leaf of the vault's allowlist root.
+    pub fn buy_val_allowlisted(
+        ctx: Context<BuyVal>,
+        sol_lamports: u64,
+        max_allocation: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::buy_val_allowlisted::handler(ctx, sol_lamports, max_allocation, proof)
+    }
+
     /// Admin updates the exchange rate.
-    /// Both numerator and denominator must be > 0.
+    /// Both numerator and denominator must be > 0, and the change must pass the vault's rate limits.
     pub fn update_exchange_rate(
         ctx: Context<UpdateExchangeRate>,
         new_numerator: u64,
@@ -75,4 +144,290 @@ pub mod vault_project {
     ) -> Result<()> {
         instructions::update_exchange_rate::handler(ctx, new_numerator, new_denominator)
     }
+
+    /// Admin announces a fixed rate change that `buy_val` switches to once
+    /// `effective_at` is reached. Replaces any previously scheduled change.
+    pub fn schedule_rate_change(
+        ctx: Context<ScheduleRateChange>,
+        numerator: u64,
+        denominator: u64,
+        effective_at: i64,
+    ) -> Result<()> {
+        instructions::schedule_rate_change::handler(ctx, numerator, denominator, effective_at)
+    }
+
+    /// Admin sets the rounding mode used when converting SOL to VAL in `buy_val`.
+    pub fn update_rounding_mode(
+        ctx: Context<UpdateRoundingMode>,
+        rounding_mode: RoundingMode,
+    ) -> Result<()> {
+        instructions::update_rounding_mode::handler(ctx, rounding_mode)
+    }
+
+    /// Admin sets the pricing curve used by `buy_val`.
+    /// Bonding curves price VAL by `total_minted`, so the marginal price rises with supply.
+    /// Oracle pricing reads a Pyth-format SOL/USD feed passed to `buy_val`.
+    /// Subject to the rate limits: curve start prices and tier rates must stay
+    /// within the bounds and `max_change_bps` of the fixed rate, and oracle
+    /// pricing is rejected while those limits are set.
+    pub fn update_price_curve(ctx: Context<UpdatePriceCurve>, price_curve: PriceCurve) -> Result<()> {
+        instructions::update_price_curve::handler(ctx, price_curve)
+    }
+
+    /// Admin replaces the supply tier table used by `PriceCurve::Tiered`.
+    /// Tiers that have already started selling must be passed through unchanged.
+    /// Each tier rate is held to the rate limits like a fixed rate update.
+    pub fn set_price_tiers(ctx: Context<SetPriceTiers>, tiers: Vec<PriceTier>) -> Result<()> {
+        instructions::set_price_tiers::handler(ctx, tiers)
+    }
+
+    /// Admin sets the window during which `buy_val` is open.
+    /// A zero `sale_start` or `sale_end` leaves that side of the window unbounded.
+    pub fn set_sale_window(ctx: Context<SetSaleWindow>, sale_start: i64, sale_end: i64) -> Result<()> {
+        instructions::set_sale_window::handler(ctx, sale_start, sale_end)
+    }
+
+    /// Admin sets the merkle root of wallets allowed to buy VAL.
+    /// A zero root opens `buy_val` to everyone.
+    pub fn set_allowlist_root(ctx: Context<SetAllowlistRoot>, allowlist_root: [u8; 32]) -> Result<()> {
+        instructions::set_allowlist_root::handler(ctx, allowlist_root)
+    }
+
+    /// Admin sets per-wallet and per-transaction purchase caps (0 = unlimited).
+    pub fn set_purchase_limits(
+        ctx: Context<SetPurchaseLimits>,
+        max_val_per_wallet: u64,
+        max_sol_per_tx: u64,
+    ) -> Result<()> {
+        instructions::set_purchase_limits::handler(ctx, max_val_per_wallet, max_sol_per_tx)
+    }
+
+    /// Admin sets the vesting schedule for purchased VAL and creates the vesting escrow.
+    /// While `vesting_duration` > 0, purchases mint into escrow and vest per buyer.
+    /// Each purchase re-anchors the buyer's position on the current schedule:
+    /// unlocked VAL stays unlocked and the rest vests again from the schedule
+    /// start, or from the purchase time when `vesting_start` is zero.
+    pub fn set_vesting_schedule(
+        ctx: Context<SetVestingSchedule>,
+        vesting_start: i64,
+        vesting_cliff: i64,
+        vesting_duration: i64,
+    ) -> Result<()> {
+        instructions::set_vesting_schedule::handler(ctx, vesting_start, vesting_cliff, vesting_duration)
+    }
+
+    /// Transfers the unlocked, unclaimed VAL of the caller's vesting position to their ATA.
+    pub fn claim_vested_val(ctx: Context<ClaimVestedVal>) -> Result<()> {
+        instructions::claim_vested_val::handler(ctx)
+    }
+
+    /// Admin sets the cap on VAL minted outside of sales (team, partners, airdrops).
+    /// Cannot be set below the VAL the admin has already minted, nor above what
+    /// sales have left of `max_supply`; sales never mint into the unminted reserve.
+    pub fn set_reserved_supply(ctx: Context<SetReservedSupply>, reserved_supply: u64) -> Result<()> {
+        instructions::set_reserved_supply::handler(ctx, reserved_supply)
+    }
+
+    /// Admin mints VAL to `recipient`'s ATA, counting against both
+    /// `max_supply` and `reserved_supply`.
+    pub fn admin_mint_val(ctx: Context<AdminMintVal>, amount: u64) -> Result<()> {
+        instructions::admin_mint_val::handler(ctx, amount)
+    }
+
+    /// Admin mints VAL to several recipients at once.
+    /// Each recipient's VAL ATA is passed as a remaining account, in the same order.
+    pub fn admin_mint_val_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdminMintValBatch<'info>>,
+        recipients: Vec<Pubkey>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::admin_mint_val_batch::handler(ctx, recipients, amounts)
+    }
+
+    /// Admin permanently revokes the VAL mint authority once the sale has ended.
+    /// After this, `buy_val` and admin minting always fail.
+    pub fn finalize_val_supply(ctx: Context<FinalizeValSupply>) -> Result<()> {
+        instructions::finalize_val_supply::handler(ctx)
+    }
+
+    /// Admin changes `max_supply`. Decreases apply immediately (never below
+    /// `total_minted`) and shrink `reserved_supply` if the unminted reserve no
+    /// longer fits; increases are queued for `MAX_SUPPLY_INCREASE_DELAY` seconds.
+    pub fn update_max_supply(ctx: Context<UpdateMaxSupply>, new_max_supply: u64) -> Result<()> {
+        instructions::update_max_supply::handler(ctx, new_max_supply)
+    }
+
+    /// Admin applies a queued `max_supply` increase once its timelock has elapsed.
+    pub fn apply_max_supply_increase(ctx: Context<ApplyMaxSupplyIncrease>) -> Result<()> {
+        instructions::apply_max_supply_increase::handler(ctx)
+    }
+
+    /// Admin proposes new exchange rate guardrails (max change per update,
+    /// min interval, min/max rate), applicable after `RATE_LIMITS_DELAY` seconds.
+    /// They also bound price curve and tier changes.
+    pub fn propose_rate_limits(ctx: Context<ProposeRateLimits>, limits: RateLimits) -> Result<()> {
+        instructions::propose_rate_limits::handler(ctx, limits)
+    }
+
+    /// Admin applies the proposed rate guardrails once their delay has elapsed.
+    pub fn apply_rate_limits(ctx: Context<ApplyRateLimits>) -> Result<()> {
+        instructions::apply_rate_limits::handler(ctx)
+    }
+
+    /// Admin whitelists an SPL mint for `buy_val_with_token` at its own rate
+    /// (whole VAL per whole token) and creates the treasury's token account.
+    pub fn set_payment_mint(
+        ctx: Context<SetPaymentMint>,
+        val_per_token_numerator: u64,
+        val_per_token_denominator: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_payment_mint::handler(
+            ctx,
+            val_per_token_numerator,
+            val_per_token_denominator,
+            enabled,
+        )
+    }
+
+    /// Buy VAL tokens with a whitelisted SPL token sent to the treasury's token account.
+    /// val_amount = token_amount * numerator / denominator, scaled by the mint decimals.
+    pub fn buy_val_with_token(ctx: Context<BuyValWithToken>, token_amount: u64) -> Result<()> {
+        instructions::buy_val_with_token::handler(ctx, token_amount)
+    }
+
+    /// Admin withdraws SPL tokens from the treasury to admin_destination's ATA.
+    /// No destination parameter - tokens can ONLY go to vault.admin_destination.
+    pub fn admin_withdraw_token(ctx: Context<AdminWithdrawToken>, amount: u64) -> Result<()> {
+        instructions::admin_withdraw_token::handler(ctx, amount)
+    }
+
+    /// Admin allows or disallows child deposits of an SPL mint and creates the
+    /// treasury's token account for it.
+    pub fn set_deposit_mint(ctx: Context<SetDepositMint>, enabled: bool) -> Result<()> {
+        instructions::set_deposit_mint::handler(ctx, enabled)
+    }
+
+    /// Deposit an allow-listed SPL token into the treasury; auto-registers the
+    /// child and credits its per-mint balance with the amount received after any
+    /// transfer fee. The deposit is locked for `lock_duration` seconds (or the
+    /// vault minimum, if longer; 0 = unlocked unless the vault sets one).
+    pub fn deposit_token(
+        ctx: Context<DepositToken>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::deposit_token::handler(ctx, amount, lock_duration)
+    }
+
+    /// Admin requests a token payout for a child, bounded by the child's
+    /// deposits of that mint minus its payouts.
+    pub fn admin_request_token_payout(
+        ctx: Context<AdminRequestTokenPayout>,
+        amount: u64,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::admin_request_token_payout::handler(ctx, amount, nonce)
+    }
+
+    /// Admin executes a pending token payout to the child authority's ATA.
+    pub fn admin_execute_token_payout(ctx: Context<AdminExecuteTokenPayout>) -> Result<()> {
+        instructions::admin_execute_token_payout::handler(ctx)
+    }
+
+    /// Same as `deposit_and_auto_register`, funded from the depositor's wSOL
+    /// token account, which is unwrapped through a temporary account.
+    pub fn deposit_wsol_and_auto_register(
+        ctx: Context<DepositWsolAndAutoRegister>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_wsol_and_auto_register::handler(ctx, amount)
+    }
+
+    /// Same as `buy_val`, funded from the buyer's wSOL token account, which is
+    /// unwrapped through a temporary account.
+    pub fn buy_val_wsol(ctx: Context<BuyValWsol>, sol_lamports: u64) -> Result<()> {
+        instructions::buy_val_wsol::handler(ctx, sol_lamports)
+    }
+
+    /// Admin sets the SOL deposit limits: minimum deposit, per-child total and
+    /// vault-wide total (0 = no limit).
+    pub fn set_deposit_limits(
+        ctx: Context<SetDepositLimits>,
+        min_deposit: u64,
+        max_deposit_per_child: u64,
+        max_total_deposits: u64,
+    ) -> Result<()> {
+        instructions::set_deposit_limits::handler(
+            ctx,
+            min_deposit,
+            max_deposit_per_child,
+            max_total_deposits,
+        )
+    }
+
+    /// Same as `deposit_and_auto_register`, locking the deposit for
+    /// `lock_duration` seconds (or the vault minimum, if longer). Locked SOL
+    /// cannot be paid out until the lock expires; each deposit keeps its own
+    /// lock while fewer than `MAX_DEPOSIT_LOCKS` are active, after which it
+    /// shares the lock expiring soonest after it (or extends the latest one).
+    pub fn deposit_with_lock(
+        ctx: Context<DepositAndAutoRegister>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::deposit_with_lock::handler(ctx, amount, lock_duration)
+    }
+
+    /// Admin sets the minimum lock applied to every SOL and token deposit (0 = opt-in only).
+    pub fn set_min_lock_duration(
+        ctx: Context<SetMinLockDuration>,
+        min_lock_duration: i64,
+    ) -> Result<()> {
+        instructions::set_min_lock_duration::handler(ctx, min_lock_duration)
+    }
+
+    /// Admin sets the key that issues depositor attestations. While set,
+    /// deposits and purchases require a valid attestation; the default key
+    /// disables the gate.
+    pub fn set_attestor(ctx: Context<SetAttestor>, attestor: Pubkey) -> Result<()> {
+        instructions::set_attestor::handler(ctx, attestor)
+    }
+
+    /// Attestor issues or renews `user`'s attestation, valid until `expires_at`.
+    pub fn issue_attestation(
+        ctx: Context<IssueAttestation>,
+        user: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::issue_attestation::handler(ctx, user, expires_at)
+    }
+
+    /// Attestor revokes an attestation, closing the account.
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        instructions::revoke_attestation::handler(ctx)
+    }
+
+    /// Admin freezes a child account (e.g. a legal hold) with a reason code.
+    /// Frozen children cannot deposit or have payouts requested or executed.
+    pub fn freeze_child(ctx: Context<FreezeChild>, reason: u8) -> Result<()> {
+        instructions::freeze_child::handler(ctx, reason)
+    }
+
+    /// Admin lifts a child account's freeze.
+    pub fn unfreeze_child(ctx: Context<FreezeChild>) -> Result<()> {
+        instructions::unfreeze_child::handler(ctx)
+    }
+
+    /// Admin extends a vault created with the original layout to the current
+    /// one. The admin pays the additional rent.
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+        instructions::migrate_vault::handler(ctx)
+    }
+
+    /// Admin extends a child account created with the original layout to the
+    /// current one, with no locks and unfrozen. The admin pays the additional rent.
+    pub fn migrate_child(ctx: Context<MigrateChild>) -> Result<()> {
+        instructions::migrate_child::handler(ctx)
+    }
 }
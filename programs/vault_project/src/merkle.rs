@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Longest accepted proof; enough for 2^32 allowlisted wallets.
+pub const MAX_PROOF_LEN: usize = 32;
+
+/// Allowlist leaf: sha256(wallet || max_allocation as little-endian u64).
+pub fn allowlist_leaf(wallet: &Pubkey, max_allocation: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &max_allocation.to_le_bytes()]).to_bytes()
+}
+
+/// Verifies a merkle proof built with sorted-pair sha256 hashing.
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (l, r) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&l, &r]).to_bytes()
+    }
+
+    #[test]
+    fn verifies_members_and_rejects_others() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets
+            .iter()
+            .zip([100u64, 200, 300])
+            .map(|(w, a)| allowlist_leaf(w, a))
+            .collect();
+
+        let ab = parent(leaves[0], leaves[1]);
+        let root = parent(ab, leaves[2]);
+
+        assert!(verify_proof(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify_proof(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify_proof(&[ab], &root, leaves[2]));
+
+        // Wrong allocation, wrong wallet, or truncated proof.
+        assert!(!verify_proof(&[leaves[1], leaves[2]], &root, allowlist_leaf(&wallets[0], 101)));
+        assert!(!verify_proof(&[ab], &root, allowlist_leaf(&Pubkey::new_unique(), 300)));
+        assert!(!verify_proof(&[leaves[1]], &root, leaves[0]));
+    }
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+
+/// Byte offsets into a Pyth v2 price account.
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+const MIN_LEN: usize = 240;
+
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_VERSION: u32 = 2;
+const PYTH_PRICE_ACCOUNT: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+
+/// Aggregate SOL/USD price read from a price feed account.
+/// The USD price of one SOL is `price * 10^expo`, with confidence `conf` in the same units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// Parses the aggregate price from a Pyth v2 price account layout.
+    pub fn from_pyth_account(data: &[u8]) -> Result<Self> {
+        require!(data.len() >= MIN_LEN, VaultError::InvalidPriceFeed);
+        require!(
+            read_u32(data, MAGIC_OFFSET) == PYTH_MAGIC
+                && read_u32(data, VERSION_OFFSET) == PYTH_VERSION
+                && read_u32(data, ACCOUNT_TYPE_OFFSET) == PYTH_PRICE_ACCOUNT,
+            VaultError::InvalidPriceFeed
+        );
+        require!(
+            read_u32(data, AGG_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+            VaultError::InvalidOraclePrice
+        );
+
+        Ok(Self {
+            price: read_u64(data, AGG_PRICE_OFFSET) as i64,
+            conf: read_u64(data, AGG_CONF_OFFSET),
+            expo: read_u32(data, EXPO_OFFSET) as i32,
+            publish_time: read_u64(data, TIMESTAMP_OFFSET) as i64,
+        })
+    }
+
+    /// Rejects non-positive, stale, or low-confidence prices.
+    pub fn validate(&self, now: i64, max_price_age: u32, max_confidence_bps: u16) -> Result<()> {
+        require!(self.price > 0, VaultError::InvalidOraclePrice);
+        require!(
+            now.saturating_sub(self.publish_time) <= max_price_age as i64,
+            VaultError::StaleOraclePrice
+        );
+        require!(
+            (self.conf as u128) * 10_000 <= (self.price as u128) * (max_confidence_bps as u128),
+            VaultError::OracleConfidenceTooWide
+        );
+        Ok(())
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pyth_account(price: i64, conf: u64, expo: i32, timestamp: i64, status: u32) -> Vec<u8> {
+        let mut data = vec![0u8; 3312];
+        data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[VERSION_OFFSET..VERSION_OFFSET + 4].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+        data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4]
+            .copy_from_slice(&PYTH_PRICE_ACCOUNT.to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&timestamp.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&status.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn parses_pyth_layout() {
+        let data = pyth_account(150_00000000, 5_000_000, -8, 1_700_000_000, 1);
+        let price = OraclePrice::from_pyth_account(&data).unwrap();
+        assert_eq!(
+            price,
+            OraclePrice {
+                price: 150_00000000,
+                conf: 5_000_000,
+                expo: -8,
+                publish_time: 1_700_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_layout_or_status() {
+        let mut data = pyth_account(1, 0, -8, 0, 1);
+        data[0] = 0;
+        assert!(OraclePrice::from_pyth_account(&data).is_err());
+        assert!(OraclePrice::from_pyth_account(&pyth_account(1, 0, -8, 0, 0)).is_err());
+        assert!(OraclePrice::from_pyth_account(&[0u8; 16]).is_err());
+    }
+
+    #[test]
+    fn rejects_stale_and_uncertain_prices() {
+        let price = OraclePrice {
+            price: 100_00000000,
+            conf: 1_00000000,
+            expo: -8,
+            publish_time: 1_000,
+        };
+        // 1% confidence interval, 60s old.
+        assert!(price.validate(1_060, 60, 100).is_ok());
+        assert!(price.validate(1_061, 60, 100).is_err());
+        assert!(price.validate(1_060, 60, 99).is_err());
+    }
+}
@@ -0,0 +1,768 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::VaultError;
+use crate::oracle::OraclePrice;
+use crate::state::{PriceCurve, PriceTier, RoundingMode, Vault, MAX_VAL_DECIMALS};
+
+/// Maximum number of curve steps a single purchase may cross.
+pub const MAX_CURVE_STEPS: usize = 64;
+
+/// Lamports in one SOL.
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Decimal exponent of `PriceCurve::Oracle::usd_price_per_val` (micro-USD).
+const USD_PRICE_DECIMALS: i32 = 6;
+
+/// Decimal exponent of lamports per SOL.
+const SOL_DECIMALS: i32 = 9;
+
+/// Basis-point denominator for exponential curve growth.
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Fixed-point scale used when compounding exponential growth.
+const GROWTH_SCALE: u128 = 1_000_000_000_000;
+
+/// Outcome of a pricing division.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quote {
+    /// Rounded result of the division.
+    pub amount: u64,
+    /// Remainder of the division before rounding (always < divisor, saturating at u64::MAX).
+    pub remainder: u64,
+}
+
+/// Computes `value * mul / div` with a u128 intermediate, rounding as requested.
+pub fn mul_div(value: u64, mul: u64, div: u64, rounding: RoundingMode) -> Result<Quote> {
+    div_round((value as u128) * (mul as u128), div as u128, rounding)
+}
+
+/// Divides a u128 numerator, rounding as requested; the result must fit in u64.
+fn div_round(numerator: u128, div: u128, rounding: RoundingMode) -> Result<Quote> {
+    require!(div > 0, VaultError::InvalidDenominator);
+
+    let quotient = numerator / div;
+    let remainder = numerator % div;
+
+    let round_up = match rounding {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => remainder > 0,
+        RoundingMode::HalfEven => {
+            let twice = remainder * 2;
+            twice > div || (twice == div && quotient % 2 == 1)
+        }
+    };
+    let amount = if round_up { quotient + 1 } else { quotient };
+
+    Ok(Quote {
+        amount: u64::try_from(amount).map_err(|_| VaultError::MathOverflow)?,
+        remainder: u64::try_from(remainder).unwrap_or(u64::MAX),
+    })
+}
+
+/// Smallest units in one whole VAL for a mint with `decimals`.
+pub fn val_unit(decimals: u8) -> Result<u64> {
+    require!(decimals <= MAX_VAL_DECIMALS, VaultError::InvalidDecimals);
+    Ok(10u64.pow(decimals as u32))
+}
+
+/// Converts smallest units into whole VAL; the remainder is the fractional part in units.
+pub fn units_to_whole(units: u64, decimals: u8) -> Result<Quote> {
+    mul_div(units, 1, val_unit(decimals)?, RoundingMode::Floor)
+}
+
+/// VAL smallest units received for `sol_lamports` at `numerator / denominator`
+/// whole VAL per whole SOL.
+pub fn val_for_sol(
+    sol_lamports: u64,
+    numerator: u64,
+    denominator: u64,
+    decimals: u8,
+    rounding: RoundingMode,
+) -> Result<Quote> {
+    val_for_token(
+        sol_lamports,
+        numerator,
+        denominator,
+        SOL_DECIMALS as u8,
+        decimals,
+        rounding,
+    )
+}
+
+/// VAL smallest units received for `token_amount` smallest units of a
+/// `token_decimals` mint at `numerator / denominator` whole VAL per whole token.
+pub fn val_for_token(
+    token_amount: u64,
+    numerator: u64,
+    denominator: u64,
+    token_decimals: u8,
+    val_decimals: u8,
+    rounding: RoundingMode,
+) -> Result<Quote> {
+    require!(numerator > 0, VaultError::InvalidNumerator);
+    let (numerator, denominator) = scale_pow10(
+        (token_amount as u128) * (numerator as u128),
+        denominator as u128,
+        val_decimals as i32 - token_decimals as i32,
+    )?;
+    div_round(numerator, denominator, rounding)
+}
+
+/// Lamports required to buy `val_amount` smallest units at `numerator / denominator`
+/// whole VAL per whole SOL. Always rounds up so the vault is never paid less
+/// than the quoted rate.
+pub fn sol_for_val(val_amount: u64, numerator: u64, denominator: u64, decimals: u8) -> Result<Quote> {
+    require!(numerator > 0, VaultError::InvalidNumerator);
+    require!(denominator > 0, VaultError::InvalidDenominator);
+    let (numerator, denominator) = scale_pow10(
+        (val_amount as u128) * (denominator as u128),
+        numerator as u128,
+        SOL_DECIMALS - decimals as i32,
+    )?;
+    div_round(numerator, denominator, RoundingMode::Ceil)
+}
+
+/// Quotes the VAL received for `sol_lamports` under the vault's pricing curve.
+/// `oracle_price` must be the validated feed price when the curve is `Oracle`.
+pub fn quote_val_for_sol(
+    vault: &Vault,
+    sol_lamports: u64,
+    oracle_price: Option<&OraclePrice>,
+) -> Result<Quote> {
+    match vault.price_curve {
+        PriceCurve::Fixed => val_for_sol(
+            sol_lamports,
+            vault.val_per_sol_numerator,
+            vault.val_per_sol_denominator,
+            vault.val_decimals,
+            vault.rounding_mode,
+        ),
+        PriceCurve::Tiered => tiered_val_for_sol(
+            vault.active_price_tiers(),
+            vault.total_minted,
+            vault.max_supply,
+            sol_lamports,
+            vault.val_decimals,
+            vault.rounding_mode,
+        ),
+        PriceCurve::Oracle { usd_price_per_val, .. } => oracle_val_for_sol(
+            oracle_price.ok_or(VaultError::InvalidPriceFeed)?,
+            usd_price_per_val,
+            sol_lamports,
+            vault.val_decimals,
+            vault.rounding_mode,
+        ),
+        curve => curve_val_for_sol(
+            &curve,
+            vault.total_minted,
+            vault.max_supply,
+            sol_lamports,
+            vault.val_decimals,
+            vault.rounding_mode,
+        ),
+    }
+}
+
+/// Quotes the lamports required for `val_amount` under the vault's pricing curve.
+/// `oracle_price` must be the validated feed price when the curve is `Oracle`.
+pub fn quote_sol_for_val(
+    vault: &Vault,
+    val_amount: u64,
+    oracle_price: Option<&OraclePrice>,
+) -> Result<Quote> {
+    match vault.price_curve {
+        PriceCurve::Fixed => sol_for_val(
+            val_amount,
+            vault.val_per_sol_numerator,
+            vault.val_per_sol_denominator,
+            vault.val_decimals,
+        ),
+        PriceCurve::Tiered => tiered_sol_for_val(
+            vault.active_price_tiers(),
+            vault.total_minted,
+            val_amount,
+            vault.val_decimals,
+        ),
+        PriceCurve::Oracle { usd_price_per_val, .. } => oracle_sol_for_val(
+            oracle_price.ok_or(VaultError::InvalidPriceFeed)?,
+            usd_price_per_val,
+            val_amount,
+            vault.val_decimals,
+        ),
+        curve => curve_sol_for_val(&curve, vault.total_minted, val_amount, vault.val_decimals),
+    }
+}
+
+/// Returns the supply left in the curve step containing `supply` and that
+/// step's price in lamports per whole VAL.
+fn current_step(curve: &PriceCurve, supply: u128) -> Result<(u128, u128)> {
+    let (step, price) = match *curve {
+        PriceCurve::Fixed | PriceCurve::Tiered | PriceCurve::Oracle { .. } => {
+            return err!(VaultError::InvalidPriceCurve)
+        }
+        PriceCurve::Linear { start_price, slope, step } => {
+            require!(step > 0, VaultError::InvalidPriceCurve);
+            let index = supply / step as u128;
+            let price = (slope as u128)
+                .checked_mul(index)
+                .and_then(|p| p.checked_add(start_price as u128))
+                .ok_or(VaultError::MathOverflow)?;
+            (step as u128, price)
+        }
+        PriceCurve::Exponential { start_price, growth_bps, step } => {
+            require!(step > 0, VaultError::InvalidPriceCurve);
+            let index = u64::try_from(supply / step as u128).map_err(|_| VaultError::MathOverflow)?;
+            let factor = (BPS_DENOMINATOR + growth_bps as u128) * GROWTH_SCALE / BPS_DENOMINATOR;
+            let price = (start_price as u128)
+                .checked_mul(pow_scaled(factor, index)?)
+                .ok_or(VaultError::MathOverflow)?
+                / GROWTH_SCALE;
+            (step as u128, price)
+        }
+    };
+    require!(price > 0, VaultError::InvalidPriceCurve);
+
+    Ok((step - supply % step, price))
+}
+
+/// Raises a `GROWTH_SCALE` fixed-point `factor` to `exp` by squaring.
+fn pow_scaled(mut factor: u128, mut exp: u64) -> Result<u128> {
+    let mul = |a: u128, b: u128| -> Result<u128> {
+        Ok(a.checked_mul(b).ok_or(VaultError::MathOverflow)? / GROWTH_SCALE)
+    };
+
+    let mut result = GROWTH_SCALE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul(result, factor)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            factor = mul(factor, factor)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Integrates a bonding curve from `total_minted` to find the VAL bought with
+/// `sol_lamports`. Whole steps are charged at their price rounded up; the final
+/// partial step uses `rounding`.
+pub fn curve_val_for_sol(
+    curve: &PriceCurve,
+    total_minted: u64,
+    max_supply: u64,
+    sol_lamports: u64,
+    decimals: u8,
+    rounding: RoundingMode,
+) -> Result<Quote> {
+    let unit = val_unit(decimals)? as u128;
+    let mut supply = total_minted as u128;
+    let mut budget = sol_lamports as u128;
+    let mut minted: u128 = 0;
+
+    for _ in 0..MAX_CURVE_STEPS {
+        require!(supply < max_supply as u128, VaultError::ExceedsMaxSupply);
+
+        let (room, price) = current_step(curve, supply)?;
+        let step_cost = room
+            .checked_mul(price)
+            .ok_or(VaultError::MathOverflow)?
+            .div_ceil(unit);
+
+        if budget < step_cost {
+            let partial = mul_div(
+                u64::try_from(budget).map_err(|_| VaultError::MathOverflow)?,
+                unit as u64,
+                u64::try_from(price).map_err(|_| VaultError::MathOverflow)?,
+                rounding,
+            )?;
+            minted += partial.amount as u128;
+            return Ok(Quote {
+                amount: u64::try_from(minted).map_err(|_| VaultError::MathOverflow)?,
+                remainder: partial.remainder,
+            });
+        }
+
+        budget -= step_cost;
+        minted += room;
+        supply += room;
+
+        if budget == 0 {
+            return Ok(Quote {
+                amount: u64::try_from(minted).map_err(|_| VaultError::MathOverflow)?,
+                remainder: 0,
+            });
+        }
+    }
+
+    err!(VaultError::CurveStepLimitExceeded)
+}
+
+/// Integrates a bonding curve from `total_minted` to price `val_amount`,
+/// rounding the total cost up to whole lamports.
+pub fn curve_sol_for_val(
+    curve: &PriceCurve,
+    total_minted: u64,
+    val_amount: u64,
+    decimals: u8,
+) -> Result<Quote> {
+    let unit = val_unit(decimals)? as u128;
+    let mut supply = total_minted as u128;
+    let mut remaining = val_amount as u128;
+    // Accumulated cost in lamports * unit, rounded once at the end.
+    let mut scaled_cost: u128 = 0;
+
+    for _ in 0..MAX_CURVE_STEPS {
+        if remaining == 0 {
+            break;
+        }
+
+        let (room, price) = current_step(curve, supply)?;
+        let take = remaining.min(room);
+
+        scaled_cost = take
+            .checked_mul(price)
+            .and_then(|c| c.checked_add(scaled_cost))
+            .ok_or(VaultError::MathOverflow)?;
+        remaining -= take;
+        supply += take;
+    }
+
+    require!(remaining == 0, VaultError::CurveStepLimitExceeded);
+
+    Ok(Quote {
+        amount: u64::try_from(scaled_cost.div_ceil(unit)).map_err(|_| VaultError::MathOverflow)?,
+        remainder: (scaled_cost % unit) as u64,
+    })
+}
+
+/// Returns the supply left in the tier containing `supply` and that tier.
+fn current_tier(tiers: &[PriceTier], supply: u64) -> Result<(u64, &PriceTier)> {
+    let tier = tiers
+        .iter()
+        .find(|t| t.supply_cap > supply)
+        .ok_or(VaultError::PriceTiersExhausted)?;
+    Ok((tier.supply_cap - supply, tier))
+}
+
+/// Splits a purchase of `sol_lamports` across supply tiers starting at
+/// `total_minted`. Whole tiers are charged rounded up; the final partial tier
+/// uses `rounding`.
+pub fn tiered_val_for_sol(
+    tiers: &[PriceTier],
+    total_minted: u64,
+    max_supply: u64,
+    sol_lamports: u64,
+    decimals: u8,
+    rounding: RoundingMode,
+) -> Result<Quote> {
+    let mut supply = total_minted;
+    let mut budget = sol_lamports;
+    let mut minted = 0u64;
+
+    loop {
+        require!(supply < max_supply, VaultError::ExceedsMaxSupply);
+
+        let (room, tier) = current_tier(tiers, supply)?;
+        let tier_cost = sol_for_val(
+            room,
+            tier.val_per_sol_numerator,
+            tier.val_per_sol_denominator,
+            decimals,
+        )?
+        .amount;
+
+        if budget < tier_cost {
+            let partial = val_for_sol(
+                budget,
+                tier.val_per_sol_numerator,
+                tier.val_per_sol_denominator,
+                decimals,
+                rounding,
+            )?;
+            return Ok(Quote {
+                amount: minted
+                    .checked_add(partial.amount)
+                    .ok_or(VaultError::MathOverflow)?,
+                remainder: partial.remainder,
+            });
+        }
+
+        budget -= tier_cost;
+        minted += room;
+        supply += room;
+
+        if budget == 0 {
+            return Ok(Quote {
+                amount: minted,
+                remainder: 0,
+            });
+        }
+    }
+}
+
+/// Prices `val_amount` across supply tiers starting at `total_minted`,
+/// rounding each tier's cost up to whole lamports.
+pub fn tiered_sol_for_val(
+    tiers: &[PriceTier],
+    total_minted: u64,
+    val_amount: u64,
+    decimals: u8,
+) -> Result<Quote> {
+    let mut supply = total_minted;
+    let mut remaining = val_amount;
+    let mut cost = 0u64;
+    let mut remainder = 0u64;
+
+    while remaining > 0 {
+        let (room, tier) = current_tier(tiers, supply)?;
+        let take = remaining.min(room);
+        let tier_cost = sol_for_val(
+            take,
+            tier.val_per_sol_numerator,
+            tier.val_per_sol_denominator,
+            decimals,
+        )?;
+
+        cost = cost
+            .checked_add(tier_cost.amount)
+            .ok_or(VaultError::MathOverflow)?;
+        remainder = tier_cost.remainder;
+        remaining -= take;
+        supply += take;
+    }
+
+    Ok(Quote {
+        amount: cost,
+        remainder,
+    })
+}
+
+/// Scales `numerator / denominator` by 10^shift, applying negative shifts to
+/// the denominator so no precision is lost.
+fn scale_pow10(numerator: u128, denominator: u128, shift: i32) -> Result<(u128, u128)> {
+    let factor = 10u128
+        .checked_pow(shift.unsigned_abs())
+        .ok_or(VaultError::MathOverflow)?;
+    let scaled = |v: u128| v.checked_mul(factor).ok_or(VaultError::MathOverflow);
+    if shift >= 0 {
+        Ok((scaled(numerator)?, denominator))
+    } else {
+        Ok((numerator, scaled(denominator)?))
+    }
+}
+
+/// Decimal shift converting `lamports * feed price` into VAL smallest units per micro-USD.
+fn oracle_shift(oracle_price: &OraclePrice, decimals: u8) -> i32 {
+    oracle_price.expo + USD_PRICE_DECIMALS + decimals as i32 - SOL_DECIMALS
+}
+
+/// VAL received for `sol_lamports` when VAL is priced at `usd_price_per_val`
+/// micro-USD and SOL at the oracle's USD price.
+pub fn oracle_val_for_sol(
+    oracle_price: &OraclePrice,
+    usd_price_per_val: u64,
+    sol_lamports: u64,
+    decimals: u8,
+    rounding: RoundingMode,
+) -> Result<Quote> {
+    require!(oracle_price.price > 0, VaultError::InvalidOraclePrice);
+    let (numerator, denominator) = scale_pow10(
+        (sol_lamports as u128) * (oracle_price.price as u128),
+        usd_price_per_val as u128,
+        oracle_shift(oracle_price, decimals),
+    )?;
+    div_round(numerator, denominator, rounding)
+}
+
+/// Lamports required for `val_amount` at the oracle price, rounded up.
+pub fn oracle_sol_for_val(
+    oracle_price: &OraclePrice,
+    usd_price_per_val: u64,
+    val_amount: u64,
+    decimals: u8,
+) -> Result<Quote> {
+    require!(oracle_price.price > 0, VaultError::InvalidOraclePrice);
+    let (denominator, numerator) = scale_pow10(
+        oracle_price.price as u128,
+        (val_amount as u128) * (usd_price_per_val as u128),
+        oracle_shift(oracle_price, decimals),
+    )?;
+    div_round(numerator, denominator, RoundingMode::Ceil)
+}
+
+/// Scale of fixed-point exchange rates compared by the rate guardrails.
+pub const RATE_SCALE: u128 = 1_000_000_000;
+
+/// `numerator / denominator` whole VAL per whole SOL, scaled by `RATE_SCALE`.
+pub fn scaled_rate(numerator: u64, denominator: u64) -> Result<u128> {
+    require!(denominator > 0, VaultError::InvalidDenominator);
+    Ok((numerator as u128) * RATE_SCALE / denominator as u128)
+}
+
+/// Rate of a curve `start_price` (lamports per whole VAL), in the units of
+/// `scaled_rate`.
+pub fn start_price_rate(start_price: u64) -> Result<u128> {
+    scaled_rate(LAMPORTS_PER_SOL, start_price)
+}
+
+/// Blended rate of a settled purchase, in VAL smallest units per whole SOL.
+/// Saturates rather than failing the purchase for extreme rates.
+pub fn effective_val_per_sol(sol_lamports: u64, val_amount: u64) -> u64 {
+    mul_div(val_amount, LAMPORTS_PER_SOL, sol_lamports, RoundingMode::Floor)
+        .map(|q| q.amount)
+        .unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(amount: u64, remainder: u64) -> Quote {
+        Quote { amount, remainder }
+    }
+
+    #[test]
+    fn floor_discards_remainder() {
+        assert_eq!(mul_div(10, 1, 3, RoundingMode::Floor).unwrap(), quote(3, 1));
+        assert_eq!(mul_div(9, 1, 3, RoundingMode::Floor).unwrap(), quote(3, 0));
+    }
+
+    #[test]
+    fn ceil_rounds_any_remainder_up() {
+        assert_eq!(mul_div(10, 1, 3, RoundingMode::Ceil).unwrap(), quote(4, 1));
+        assert_eq!(mul_div(9, 1, 3, RoundingMode::Ceil).unwrap(), quote(3, 0));
+    }
+
+    #[test]
+    fn half_even_rounds_ties_to_even() {
+        assert_eq!(mul_div(5, 1, 2, RoundingMode::HalfEven).unwrap(), quote(2, 1));
+        assert_eq!(mul_div(7, 1, 2, RoundingMode::HalfEven).unwrap(), quote(4, 1));
+        assert_eq!(mul_div(10, 1, 4, RoundingMode::HalfEven).unwrap(), quote(2, 2));
+        assert_eq!(mul_div(11, 1, 4, RoundingMode::HalfEven).unwrap(), quote(3, 3));
+        assert_eq!(mul_div(9, 1, 4, RoundingMode::HalfEven).unwrap(), quote(2, 1));
+    }
+
+    #[test]
+    fn large_intermediate_does_not_overflow() {
+        // 1M SOL at 1e6 VAL per lamport overflows u64 in the product but not in the result.
+        let sol = 1_000_000 * 1_000_000_000u64;
+        let q = mul_div(sol, 1_000_000, 1_000_000, RoundingMode::Floor).unwrap();
+        assert_eq!(q, quote(sol, 0));
+    }
+
+    #[test]
+    fn result_exceeding_u64_is_an_error() {
+        assert!(mul_div(u64::MAX, 2, 1, RoundingMode::Floor).is_err());
+    }
+
+    #[test]
+    fn zero_divisors_are_rejected() {
+        assert!(mul_div(1, 1, 0, RoundingMode::Floor).is_err());
+        assert!(val_for_sol(1, 0, 1, DEC, RoundingMode::Floor).is_err());
+        assert!(sol_for_val(1, 1, 0, DEC).is_err());
+    }
+
+    #[test]
+    fn sol_for_val_covers_requested_val() {
+        for (val, num, den) in [(1_000u64, 3u64, 7u64), (999, 1_000, 1), (1, 5, 2)] {
+            let sol = sol_for_val(val, num, den, DEC).unwrap().amount;
+            let back = val_for_sol(sol, num, den, DEC, RoundingMode::Floor).unwrap().amount;
+            assert!(back >= val);
+        }
+    }
+
+    const WHOLE: u64 = 1_000_000_000;
+    const DEC: u8 = 9;
+
+    #[test]
+    fn linear_curve_charges_each_step_at_its_price() {
+        // 1 SOL per VAL for the first 10 VAL, 2 SOL per VAL for the next 10.
+        let curve = PriceCurve::Linear {
+            start_price: WHOLE,
+            slope: WHOLE,
+            step: 10 * WHOLE,
+        };
+
+        let cost = curve_sol_for_val(&curve, 0, 15 * WHOLE, DEC).unwrap();
+        assert_eq!(cost, quote(20 * WHOLE, 0));
+
+        let bought = curve_val_for_sol(&curve, 0, u64::MAX, 20 * WHOLE, DEC, RoundingMode::Floor).unwrap();
+        assert_eq!(bought, quote(15 * WHOLE, 0));
+    }
+
+    #[test]
+    fn linear_curve_price_rises_with_supply() {
+        let curve = PriceCurve::Linear {
+            start_price: WHOLE,
+            slope: WHOLE,
+            step: 10 * WHOLE,
+        };
+
+        let early = curve_val_for_sol(&curve, 0, u64::MAX, WHOLE, DEC, RoundingMode::Floor).unwrap();
+        let late = curve_val_for_sol(&curve, 30 * WHOLE, u64::MAX, WHOLE, DEC, RoundingMode::Floor).unwrap();
+        assert_eq!(early.amount, WHOLE);
+        assert_eq!(late.amount, WHOLE / 4);
+    }
+
+    #[test]
+    fn exponential_curve_compounds_per_step() {
+        // Price doubles every 10 VAL.
+        let curve = PriceCurve::Exponential {
+            start_price: WHOLE,
+            growth_bps: 10_000,
+            step: 10 * WHOLE,
+        };
+
+        let cost = curve_sol_for_val(&curve, 20 * WHOLE, 10 * WHOLE, DEC).unwrap();
+        assert_eq!(cost.amount, 40 * WHOLE);
+
+        let bought = curve_val_for_sol(&curve, 0, u64::MAX, 30 * WHOLE, DEC, RoundingMode::Floor).unwrap();
+        assert_eq!(bought.amount, 20 * WHOLE);
+    }
+
+    #[test]
+    fn curve_round_trip_covers_requested_val() {
+        let curve = PriceCurve::Exponential {
+            start_price: 3_333,
+            growth_bps: 125,
+            step: 7 * WHOLE,
+        };
+        for (minted, val) in [(0u64, 1u64), (5 * WHOLE, 13 * WHOLE + 17), (40 * WHOLE, 999_999)] {
+            let sol = curve_sol_for_val(&curve, minted, val, DEC).unwrap().amount;
+            let back = curve_val_for_sol(&curve, minted, u64::MAX, sol, DEC, RoundingMode::Floor).unwrap();
+            assert!(back.amount >= val);
+        }
+    }
+
+    #[test]
+    fn curve_purchase_stops_at_max_supply() {
+        let curve = PriceCurve::Linear {
+            start_price: WHOLE,
+            slope: 0,
+            step: 10 * WHOLE,
+        };
+        assert!(curve_val_for_sol(&curve, 0, 10 * WHOLE, 11 * WHOLE, DEC, RoundingMode::Floor).is_err());
+        assert!(curve_val_for_sol(&curve, 0, 10 * WHOLE, 10 * WHOLE, DEC, RoundingMode::Floor).is_ok());
+    }
+
+    #[test]
+    fn curve_purchase_is_bounded_by_step_limit() {
+        let curve = PriceCurve::Linear {
+            start_price: 1,
+            slope: 0,
+            step: 1,
+        };
+        assert!(curve_sol_for_val(&curve, 0, MAX_CURVE_STEPS as u64 + 1, DEC).is_err());
+        assert!(curve_sol_for_val(&curve, 0, MAX_CURVE_STEPS as u64, DEC).is_ok());
+    }
+
+    fn tiers() -> Vec<PriceTier> {
+        // 2 VAL per lamport for the first 10 VAL, 1 VAL per lamport for the next 20.
+        vec![
+            PriceTier {
+                supply_cap: 10 * WHOLE,
+                val_per_sol_numerator: 2,
+                val_per_sol_denominator: 1,
+            },
+            PriceTier {
+                supply_cap: 30 * WHOLE,
+                val_per_sol_numerator: 1,
+                val_per_sol_denominator: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn tiered_purchase_splits_across_boundary() {
+        let bought = tiered_val_for_sol(&tiers(), 8 * WHOLE, u64::MAX, 3 * WHOLE, DEC, RoundingMode::Floor).unwrap();
+        // 1 SOL buys the last 2 VAL of tier one, 2 SOL buy 2 VAL of tier two.
+        assert_eq!(bought, quote(4 * WHOLE, 0));
+
+        let cost = tiered_sol_for_val(&tiers(), 8 * WHOLE, 4 * WHOLE, DEC).unwrap();
+        assert_eq!(cost.amount, 3 * WHOLE);
+    }
+
+    #[test]
+    fn tiered_purchase_rejects_past_last_tier() {
+        assert!(tiered_sol_for_val(&tiers(), 0, 30 * WHOLE + 1, DEC).is_err());
+        assert!(tiered_val_for_sol(&tiers(), 0, u64::MAX, 26 * WHOLE, DEC, RoundingMode::Floor).is_err());
+        assert!(tiered_val_for_sol(&tiers(), 0, u64::MAX, 25 * WHOLE, DEC, RoundingMode::Floor).is_ok());
+    }
+
+    #[test]
+    fn units_convert_at_any_decimals() {
+        assert_eq!(val_unit(6).unwrap(), 1_000_000);
+        assert_eq!(units_to_whole(2_500_000, 6).unwrap(), quote(2, 500_000));
+        assert!(val_unit(MAX_VAL_DECIMALS + 1).is_err());
+    }
+
+    #[test]
+    fn fixed_rate_is_whole_val_per_whole_sol() {
+        // 3 VAL per SOL: one SOL buys 3 whole VAL regardless of mint decimals.
+        for decimals in [0u8, 6, 9, 12] {
+            let unit = val_unit(decimals).unwrap();
+            let bought = val_for_sol(WHOLE, 3, 1, decimals, RoundingMode::Floor).unwrap();
+            assert_eq!(bought.amount, 3 * unit);
+            assert_eq!(sol_for_val(3 * unit, 3, 1, decimals).unwrap(), quote(WHOLE, 0));
+        }
+    }
+
+    #[test]
+    fn token_rate_scales_between_mint_decimals() {
+        // 20 VAL per USDC: 1.5 USDC (6 decimals) buys 30 VAL (9 decimals).
+        let bought = val_for_token(1_500_000, 20, 1, 6, DEC, RoundingMode::Floor).unwrap();
+        assert_eq!(bought, quote(30 * WHOLE, 0));
+        // 1 VAL per 3 tokens, rounding the 6-decimal result.
+        let bought = val_for_token(1_000_000, 1, 3, 6, 6, RoundingMode::HalfEven).unwrap();
+        assert_eq!(bought.amount, 333_333);
+    }
+
+    #[test]
+    fn oracle_scales_to_mint_decimals() {
+        let bought = oracle_val_for_sol(&sol_at_150_usd(), 50_000, WHOLE, 6, RoundingMode::Floor).unwrap();
+        assert_eq!(bought, quote(3_000_000_000, 0));
+    }
+
+    #[test]
+    fn effective_rate_reports_blend() {
+        assert_eq!(effective_val_per_sol(3 * WHOLE, 4 * WHOLE), 4 * WHOLE / 3);
+        assert_eq!(effective_val_per_sol(1, u64::MAX), u64::MAX);
+    }
+
+    fn sol_at_150_usd() -> OraclePrice {
+        OraclePrice {
+            price: 150_00000000,
+            conf: 0,
+            expo: -8,
+            publish_time: 0,
+        }
+    }
+
+    #[test]
+    fn oracle_converts_through_usd() {
+        // 1 SOL = $150, 1 VAL = $0.05 => 3000 VAL per SOL.
+        let bought = oracle_val_for_sol(&sol_at_150_usd(), 50_000, WHOLE, DEC, RoundingMode::Floor).unwrap();
+        assert_eq!(bought, quote(3_000 * WHOLE, 0));
+
+        let cost = oracle_sol_for_val(&sol_at_150_usd(), 50_000, 3_000 * WHOLE, DEC).unwrap();
+        assert_eq!(cost, quote(WHOLE, 0));
+    }
+
+    #[test]
+    fn oracle_sol_for_val_rounds_up() {
+        // 1 VAL = $7 at $150/SOL costs 46_666_666.67 lamports.
+        let cost = oracle_sol_for_val(&sol_at_150_usd(), 7_000_000, WHOLE, DEC).unwrap();
+        assert_eq!(cost.amount, 46_666_667);
+        let back = oracle_val_for_sol(&sol_at_150_usd(), 7_000_000, cost.amount, DEC, RoundingMode::Floor).unwrap();
+        assert!(back.amount >= WHOLE);
+    }
+
+    #[test]
+    fn oracle_rejects_non_positive_price() {
+        let mut price = sol_at_150_usd();
+        price.price = 0;
+        assert!(oracle_val_for_sol(&price, 1, WHOLE, DEC, RoundingMode::Floor).is_err());
+        assert!(oracle_sol_for_val(&price, 1, WHOLE, DEC).is_err());
+    }
+}
@@ -1,14 +1,18 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::VaultError;
+use crate::pricing;
+
 #[account]
+#[derive(Default)]
 pub struct Vault {
     /// The admin authority who controls the vault.
     pub admin_authority: Pubkey,
     /// Fixed destination for admin withdrawals (SOL goes here only).
     pub admin_destination: Pubkey,
-    /// Exchange rate numerator: val_amount = sol_lamports * numerator / denominator
+    /// Exchange rate numerator, in whole VAL per whole SOL (numerator / denominator).
     pub val_per_sol_numerator: u64,
-    /// Exchange rate denominator: val_amount = sol_lamports * numerator / denominator
+    /// Exchange rate denominator, in whole VAL per whole SOL (numerator / denominator).
     pub val_per_sol_denominator: u64,
     /// Maximum VAL tokens that can ever be minted (in smallest units).
     pub max_supply: u64,
@@ -24,10 +28,107 @@ pub struct Vault {
     pub vault_bump: u8,
     /// Bump seed for the treasury PDA.
     pub treasury_bump: u8,
+    // Fields below were added after the original layout; `migrate_vault`
+    // extends vaults created before them.
+    /// Rounding applied when converting SOL to VAL in `buy_val`.
+    pub rounding_mode: RoundingMode,
+    /// Pricing curve used by `buy_val`; `Fixed` uses the numerator/denominator rate.
+    pub price_curve: PriceCurve,
+    /// Supply tiers used by `PriceCurve::Tiered`; only the first `price_tier_count` are active.
+    pub price_tiers: [PriceTier; MAX_PRICE_TIERS],
+    /// Number of active entries in `price_tiers`.
+    pub price_tier_count: u8,
+    /// Unix timestamp when VAL sales open (0 = no start restriction).
+    pub sale_start: i64,
+    /// Unix timestamp when VAL sales close (0 = no end restriction).
+    pub sale_end: i64,
+    /// Merkle root of `(wallet, max_allocation)` leaves allowed to buy VAL (zero = open sale).
+    pub allowlist_root: [u8; 32],
+    /// Maximum VAL a single wallet may buy across all purchases (0 = unlimited).
+    pub max_val_per_wallet: u64,
+    /// Maximum lamports a single purchase may spend (0 = unlimited).
+    pub max_sol_per_tx: u64,
+    /// Vesting start for purchased VAL (0 = vesting starts at each purchase).
+    pub vesting_start: i64,
+    /// Seconds after the vesting start before any VAL unlocks.
+    pub vesting_cliff: i64,
+    /// Seconds after the vesting start until all VAL is unlocked (0 = VAL is minted immediately).
+    pub vesting_duration: i64,
+    /// Decimals of the VAL mint, fixed by `initialize_val_mint`.
+    pub val_decimals: u8,
+    /// Maximum VAL the admin may mint outside of sales (in smallest units). The
+    /// unminted part is held back from sales.
+    pub reserved_supply: u64,
+    /// VAL minted by the admin so far, counted in `total_minted` as well.
+    pub reserved_minted: u64,
+    /// Set once the mint authority has been revoked; no more VAL can be minted.
+    pub supply_finalized: bool,
+    /// Queued `max_supply` increase (0 = none pending).
+    pub pending_max_supply: u64,
+    /// Unix timestamp after which `pending_max_supply` may be applied.
+    pub pending_max_supply_at: i64,
+    /// Guardrails enforced by `update_exchange_rate`.
+    pub rate_limits: RateLimits,
+    /// Guardrails proposed by `propose_rate_limits`, applied after `RATE_LIMITS_DELAY`.
+    pub pending_rate_limits: RateLimits,
+    /// Unix timestamp after which `pending_rate_limits` may be applied (0 = none pending).
+    pub pending_rate_limits_at: i64,
+    /// Unix timestamp of the last exchange rate update (0 = never updated).
+    pub last_rate_update: i64,
+    /// Numerator of the scheduled exchange rate.
+    pub scheduled_numerator: u64,
+    /// Denominator of the scheduled exchange rate.
+    pub scheduled_denominator: u64,
+    /// Unix timestamp when the scheduled rate takes effect (0 = none scheduled).
+    pub scheduled_rate_at: i64,
+    /// Smallest SOL deposit accepted, in lamports (0 = no minimum).
+    pub min_deposit: u64,
+    /// Maximum lamports a single child may deposit in total (0 = unlimited).
+    pub max_deposit_per_child: u64,
+    /// Maximum lamports the vault accepts across all deposits (0 = unlimited).
+    pub max_total_deposits: u64,
+    /// Minimum seconds every SOL and token deposit is locked for (0 = locks are opt-in).
+    pub min_lock_duration: i64,
+    /// Key that issues depositor attestations; while set, deposits and
+    /// purchases require a valid one (default = no attestation gate).
+    pub attestor: Pubkey,
 }
 
 impl Vault {
-    pub const LEN: usize = 8   // anchor discriminator
+    pub const LEN: usize = Self::LEGACY_LEN
+        + 1   // rounding_mode
+        + PriceCurve::LEN // price_curve
+        + PriceTier::LEN * MAX_PRICE_TIERS // price_tiers
+        + 1   // price_tier_count
+        + 8   // sale_start
+        + 8   // sale_end
+        + 32  // allowlist_root
+        + 8   // max_val_per_wallet
+        + 8   // max_sol_per_tx
+        + 8   // vesting_start
+        + 8   // vesting_cliff
+        + 8   // vesting_duration
+        + 1   // val_decimals
+        + 8   // reserved_supply
+        + 8   // reserved_minted
+        + 1   // supply_finalized
+        + 8   // pending_max_supply
+        + 8   // pending_max_supply_at
+        + RateLimits::LEN // rate_limits
+        + RateLimits::LEN // pending_rate_limits
+        + 8   // pending_rate_limits_at
+        + 8   // last_rate_update
+        + 8   // scheduled_numerator
+        + 8   // scheduled_denominator
+        + 8   // scheduled_rate_at
+        + 8   // min_deposit
+        + 8   // max_deposit_per_child
+        + 8   // max_total_deposits
+        + 8   // min_lock_duration
+        + 32; // attestor
+
+    /// Size of vaults created before any fields were appended.
+    pub const LEGACY_LEN: usize = 8   // anchor discriminator
         + 32  // admin_authority
         + 32  // admin_destination
         + 8   // val_per_sol_numerator
@@ -42,14 +143,550 @@ impl Vault {
 
     pub const SEED_PREFIX: &'static [u8] = b"vault";
     pub const TREASURY_SEED: &'static [u8] = b"treasury";
+
+    /// Rejects purchases outside the configured sale window.
+    pub fn require_sale_open(&self, now: i64) -> Result<()> {
+        require!(
+            self.sale_start == 0 || now >= self.sale_start,
+            VaultError::SaleNotStarted
+        );
+        require!(
+            self.sale_end == 0 || now < self.sale_end,
+            VaultError::SaleEnded
+        );
+        Ok(())
+    }
+
+    /// Whether purchases must go through `buy_val_allowlisted`.
+    pub fn allowlist_enabled(&self) -> bool {
+        self.allowlist_root != [0u8; 32]
+    }
+
+    /// Whether deposits and purchases require an attestation from `attestor`.
+    pub fn attestation_enabled(&self) -> bool {
+        self.attestor != Pubkey::default()
+    }
+
+    /// Requires `user` to present an unexpired attestation issued by the
+    /// current attestor while the attestation gate is enabled.
+    pub fn require_attestation(
+        &self,
+        user: &Pubkey,
+        attestation: Option<&Attestation>,
+        now: i64,
+    ) -> Result<()> {
+        if !self.attestation_enabled() {
+            return Ok(());
+        }
+        let attestation = attestation.ok_or(VaultError::AttestationRequired)?;
+        require!(
+            attestation.attestor == self.attestor && attestation.user == *user,
+            VaultError::InvalidAttestation
+        );
+        require!(now < attestation.expires_at, VaultError::AttestationExpired);
+        Ok(())
+    }
+
+    /// Whether purchased VAL is locked in vesting positions instead of minted to the buyer.
+    pub fn vesting_enabled(&self) -> bool {
+        self.vesting_duration > 0
+    }
+
+    /// Sets the VAL mint decimals, requiring `max_supply` to be a whole number of VAL.
+    pub fn set_val_decimals(&mut self, decimals: u8) -> Result<()> {
+        require!(decimals <= MAX_VAL_DECIMALS, VaultError::InvalidDecimals);
+        require_whole_supply(self.max_supply, decimals)?;
+        self.val_decimals = decimals;
+        Ok(())
+    }
+
+    /// Lowers `max_supply` immediately, cancelling any queued increase. It
+    /// cannot drop below `total_minted`; `reserved_supply` shrinks with it if
+    /// the unminted reserve no longer fits. Returns whether it did.
+    pub fn decrease_max_supply(&mut self, max_supply: u64) -> Result<bool> {
+        require!(
+            max_supply <= self.max_supply && max_supply >= self.total_minted,
+            VaultError::InvalidMaxSupply
+        );
+        self.max_supply = max_supply;
+        self.pending_max_supply = 0;
+        self.pending_max_supply_at = 0;
+
+        let sold = self
+            .total_minted
+            .checked_sub(self.reserved_minted)
+            .ok_or(VaultError::MathOverflow)?;
+        let reserved_cap = max_supply - sold;
+        let clamped = self.reserved_supply > reserved_cap;
+        if clamped {
+            self.reserved_supply = reserved_cap;
+        }
+        Ok(clamped)
+    }
+
+    /// Queues a `max_supply` increase that can be applied after `MAX_SUPPLY_INCREASE_DELAY`.
+    pub fn queue_max_supply_increase(&mut self, max_supply: u64, now: i64) -> Result<()> {
+        self.require_not_finalized()?;
+        require!(max_supply > self.max_supply, VaultError::InvalidMaxSupply);
+        require_whole_supply(max_supply, self.val_decimals)?;
+        self.pending_max_supply = max_supply;
+        self.pending_max_supply_at = now
+            .checked_add(MAX_SUPPLY_INCREASE_DELAY)
+            .ok_or(VaultError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Applies the queued `max_supply` increase once its delay has elapsed.
+    pub fn apply_max_supply_increase(&mut self, now: i64) -> Result<()> {
+        self.require_not_finalized()?;
+        require!(self.pending_max_supply > 0, VaultError::NoPendingMaxSupply);
+        require!(now >= self.pending_max_supply_at, VaultError::TimelockNotElapsed);
+        self.max_supply = self.pending_max_supply;
+        self.pending_max_supply = 0;
+        self.pending_max_supply_at = 0;
+        Ok(())
+    }
+
+    /// Rejects minting once the VAL supply has been finalized.
+    pub fn require_not_finalized(&self) -> Result<()> {
+        require!(!self.supply_finalized, VaultError::SupplyFinalized);
+        Ok(())
+    }
+
+    /// Checks a SOL deposit of `amount` that brings a child's deposits to
+    /// `child_total_deposited` against the deposit limits, and counts it in
+    /// `total_deposited`.
+    pub fn record_deposit(&mut self, amount: u64, child_total_deposited: u64) -> Result<()> {
+        require!(amount >= self.min_deposit, VaultError::DepositBelowMinimum);
+        require!(
+            self.max_deposit_per_child == 0 || child_total_deposited <= self.max_deposit_per_child,
+            VaultError::ExceedsChildDepositLimit
+        );
+        let total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            self.max_total_deposits == 0 || total_deposited <= self.max_total_deposits,
+            VaultError::ExceedsTotalDepositLimit
+        );
+        self.total_deposited = total_deposited;
+        Ok(())
+    }
+
+    /// Unlock time for a deposit at `now` locked for `lock_duration` seconds,
+    /// raised to the vault minimum (0 = the deposit is not locked).
+    pub fn deposit_locked_until(&self, lock_duration: i64, now: i64) -> Result<i64> {
+        require!(lock_duration >= 0, VaultError::InvalidLockDuration);
+        let duration = lock_duration.max(self.min_lock_duration);
+        if duration == 0 {
+            return Ok(0);
+        }
+        Ok(now.checked_add(duration).ok_or(VaultError::MathOverflow)?)
+    }
+
+    /// Counts an admin mint of `amount` against both `max_supply` and `reserved_supply`.
+    pub fn record_admin_mint(&mut self, amount: u64) -> Result<()> {
+        self.require_not_finalized()?;
+        let total_minted = self
+            .total_minted
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(total_minted <= self.max_supply, VaultError::ExceedsMaxSupply);
+        let reserved_minted = self
+            .reserved_minted
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            reserved_minted <= self.reserved_supply,
+            VaultError::ExceedsReservedSupply
+        );
+        self.total_minted = total_minted;
+        self.reserved_minted = reserved_minted;
+        Ok(())
+    }
+
+    /// Reserved VAL the admin has not minted yet.
+    pub fn reserved_remaining(&self) -> u64 {
+        self.reserved_supply.saturating_sub(self.reserved_minted)
+    }
+
+    /// Counts a sale of `amount` VAL against `max_supply`, leaving the unminted
+    /// reserved supply for admin mints.
+    pub fn record_sale_mint(&mut self, amount: u64) -> Result<()> {
+        self.require_not_finalized()?;
+        let total_minted = self
+            .total_minted
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            total_minted <= self.max_supply.saturating_sub(self.reserved_remaining()),
+            VaultError::ExceedsMaxSupply
+        );
+        self.total_minted = total_minted;
+        Ok(())
+    }
+
+    /// Sets `reserved_supply`, which must cover what the admin already minted
+    /// and fit in `max_supply` next to the VAL already sold.
+    pub fn set_reserved_supply(&mut self, reserved_supply: u64) -> Result<()> {
+        let sold = self
+            .total_minted
+            .checked_sub(self.reserved_minted)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(
+            reserved_supply >= self.reserved_minted
+                && sold
+                    .checked_add(reserved_supply)
+                    .is_some_and(|total| total <= self.max_supply),
+            VaultError::InvalidReservedSupply
+        );
+        self.reserved_supply = reserved_supply;
+        Ok(())
+    }
+
+    /// Checks a new fixed exchange rate against the vault's rate guardrails.
+    pub fn check_rate_update(&self, numerator: u64, denominator: u64, now: i64) -> Result<()> {
+        require!(
+            self.last_rate_update == 0
+                || now >= self.last_rate_update.saturating_add(self.rate_limits.min_update_interval),
+            VaultError::RateUpdateTooSoon
+        );
+        self.check_rate_limits(pricing::scaled_rate(numerator, denominator)?)
+    }
+
+    /// Checks a new price curve against the rate bounds and `max_change_bps`.
+    /// Linear and exponential curves are checked at their start price and
+    /// `Tiered` at each active tier's rate. An oracle price cannot be bounded
+    /// up front, so it is rejected while any of those limits is set.
+    pub fn check_price_curve(&self, curve: &PriceCurve) -> Result<()> {
+        match *curve {
+            PriceCurve::Fixed => Ok(()),
+            PriceCurve::Linear { start_price, .. }
+            | PriceCurve::Exponential { start_price, .. } => {
+                self.check_rate_limits(pricing::start_price_rate(start_price)?)
+            }
+            PriceCurve::Tiered => self.check_price_tiers(self.active_price_tiers()),
+            PriceCurve::Oracle { .. } => {
+                let limits = &self.rate_limits;
+                require!(
+                    limits.max_change_bps == 0 && limits.min_rate == 0 && limits.max_rate == 0,
+                    VaultError::RateLimitsActive
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks each tier's rate against the rate bounds and `max_change_bps`.
+    pub fn check_price_tiers(&self, tiers: &[PriceTier]) -> Result<()> {
+        for tier in tiers {
+            self.check_rate_limits(pricing::scaled_rate(
+                tier.val_per_sol_numerator,
+                tier.val_per_sol_denominator,
+            )?)?;
+        }
+        Ok(())
+    }
+
+    /// Checks a scaled rate against the rate bounds and against the current
+    /// fixed rate by `max_change_bps`.
+    fn check_rate_limits(&self, new_rate: u128) -> Result<()> {
+        let limits = &self.rate_limits;
+        require!(
+            (limits.min_rate == 0 || new_rate >= limits.min_rate as u128)
+                && (limits.max_rate == 0 || new_rate <= limits.max_rate as u128),
+            VaultError::RateOutOfBounds
+        );
+
+        if limits.max_change_bps > 0 {
+            let old_rate = pricing::scaled_rate(
+                self.val_per_sol_numerator,
+                self.val_per_sol_denominator,
+            )?;
+            let change = new_rate.abs_diff(old_rate);
+            require!(
+                change * BPS_DENOMINATOR <= old_rate * limits.max_change_bps as u128,
+                VaultError::RateChangeTooLarge
+            );
+        }
+        Ok(())
+    }
+
+    /// Throttles a price curve or tier table change by the rate limits'
+    /// `min_update_interval` and records it as the latest price update.
+    pub fn record_pricing_change(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.last_rate_update == 0
+                || now >= self
+                    .last_rate_update
+                    .saturating_add(self.rate_limits.min_update_interval),
+            VaultError::RateUpdateTooSoon
+        );
+        self.last_rate_update = now;
+        Ok(())
+    }
+
+    /// Switches to the scheduled exchange rate once `now` reaches its effective
+    /// time. Returns whether the rate changed.
+    pub fn apply_scheduled_rate(&mut self, now: i64) -> bool {
+        if self.scheduled_rate_at == 0 || now < self.scheduled_rate_at {
+            return false;
+        }
+        self.val_per_sol_numerator = self.scheduled_numerator;
+        self.val_per_sol_denominator = self.scheduled_denominator;
+        self.last_rate_update = self.scheduled_rate_at;
+        self.clear_scheduled_rate();
+        true
+    }
+
+    /// Drops any scheduled exchange rate change.
+    pub fn clear_scheduled_rate(&mut self) {
+        self.scheduled_numerator = 0;
+        self.scheduled_denominator = 0;
+        self.scheduled_rate_at = 0;
+    }
+
+    /// Applies the proposed rate guardrails once their delay has elapsed.
+    pub fn apply_rate_limits(&mut self, now: i64) -> Result<()> {
+        require!(self.pending_rate_limits_at > 0, VaultError::NoPendingRateLimits);
+        require!(now >= self.pending_rate_limits_at, VaultError::TimelockNotElapsed);
+        self.rate_limits = self.pending_rate_limits;
+        self.pending_rate_limits = RateLimits::default();
+        self.pending_rate_limits_at = 0;
+        Ok(())
+    }
+
+    /// The active prefix of the price tier table.
+    pub fn active_price_tiers(&self) -> &[PriceTier] {
+        &self.price_tiers[..self.price_tier_count as usize]
+    }
+}
+
+/// Requires `max_supply` to be a whole number of VAL at `decimals`.
+fn require_whole_supply(max_supply: u64, decimals: u8) -> Result<()> {
+    require!(
+        pricing::units_to_whole(max_supply, decimals)?.remainder == 0,
+        VaultError::MaxSupplyNotRepresentable
+    );
+    Ok(())
+}
+
+/// Rounding policy for VAL pricing divisions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round toward zero (the remainder stays with the vault).
+    #[default]
+    Floor,
+    /// Round up whenever there is a remainder.
+    Ceil,
+    /// Round to nearest, ties to even (banker's rounding).
+    HalfEven,
+}
+
+/// Pricing curve for VAL sales, parameterized on `Vault::total_minted`.
+/// Curve prices are lamports per whole VAL (10^val_decimals smallest units) and
+/// change every `step` smallest units minted.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PriceCurve {
+    /// Fixed rate of numerator / denominator whole VAL per whole SOL.
+    #[default]
+    Fixed,
+    /// price = start_price + slope * (total_minted / step)
+    Linear {
+        start_price: u64,
+        slope: u64,
+        step: u64,
+    },
+    /// price = start_price * (1 + growth_bps / 10_000) ^ (total_minted / step)
+    Exponential {
+        start_price: u64,
+        growth_bps: u16,
+        step: u64,
+    },
+    /// Fixed rate per supply band, taken from `Vault::price_tiers`.
+    Tiered,
+    /// USD-denominated price converted at the SOL/USD price read from `price_feed`.
+    Oracle {
+        /// Pyth-format SOL/USD price account.
+        price_feed: Pubkey,
+        /// Price of one whole VAL in micro-USD (1e-6 USD).
+        usd_price_per_val: u64,
+        /// Maximum age of the feed's publish time, in seconds.
+        max_price_age: u32,
+        /// Maximum confidence interval relative to price, in basis points.
+        max_confidence_bps: u16,
+    },
+}
+
+impl PriceCurve {
+    pub const LEN: usize = 1 + 32 + 8 + 4 + 2; // tag + largest variant
+}
+
+/// Seconds a `max_supply` increase must wait before it can be applied (2 days).
+pub const MAX_SUPPLY_INCREASE_DELAY: i64 = 2 * 24 * 60 * 60;
+
+/// Maximum number of supply tiers a vault can hold.
+pub const MAX_PRICE_TIERS: usize = 8;
+
+/// A supply band sold at a fixed rate. The band runs from the previous tier's
+/// `supply_cap` (or zero) up to this tier's `supply_cap`, in smallest units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PriceTier {
+    /// Cumulative supply at which this tier ends.
+    pub supply_cap: u64,
+    /// Tier rate numerator, in whole VAL per whole SOL (numerator / denominator).
+    pub val_per_sol_numerator: u64,
+    /// Tier rate denominator, in whole VAL per whole SOL (numerator / denominator).
+    pub val_per_sol_denominator: u64,
+}
+
+impl PriceTier {
+    pub const LEN: usize = 8 + 8 + 8;
+}
+
+/// Guardrails on exchange rate, price curve and tier updates. Rates are whole
+/// VAL per whole SOL scaled by `pricing::RATE_SCALE`; zero disables a limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimits {
+    /// Maximum change per update, in basis points of the current rate.
+    pub max_change_bps: u16,
+    /// Minimum seconds between rate updates.
+    pub min_update_interval: i64,
+    /// Lowest rate an update may set.
+    pub min_rate: u64,
+    /// Highest rate an update may set.
+    pub max_rate: u64,
+}
+
+impl RateLimits {
+    pub const LEN: usize = 2 + 8 + 8 + 8;
+
+    /// Rejects inconsistent limits.
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min_update_interval >= 0, VaultError::InvalidRateLimits);
+        require!(
+            self.min_rate == 0 || self.max_rate == 0 || self.min_rate <= self.max_rate,
+            VaultError::InvalidRateLimits
+        );
+        Ok(())
+    }
 }
 
+/// Seconds proposed rate guardrails must wait before they can be applied (2 days).
+pub const RATE_LIMITS_DELAY: i64 = 2 * 24 * 60 * 60;
+
+/// Basis-point denominator for rate change limits.
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Default decimals of the VAL mint, matching SOL.
+pub const DEFAULT_VAL_DECIMALS: u8 = 9;
+
+/// Largest VAL decimals for which one whole VAL fits in a u64.
+pub const MAX_VAL_DECIMALS: u8 = 18;
+
+/// Maximum number of deposits a child balance can have locked at once.
+pub const MAX_DEPOSIT_LOCKS: usize = 8;
+
 pub const CHILD_SEED: &[u8] = b"child";
 pub const PAYOUT_SEED: &[u8] = b"payout";
 pub const VAL_MINT_SEED: &[u8] = b"val_mint";
 pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+pub const PURCHASE_RECORD_SEED: &[u8] = b"purchase";
+pub const VESTING_SEED: &[u8] = b"vesting";
+pub const VESTING_ESCROW_SEED: &[u8] = b"vesting_escrow";
+pub const PAYMENT_MINT_SEED: &[u8] = b"payment_mint";
+pub const DEPOSIT_MINT_SEED: &[u8] = b"deposit_mint";
+pub const CHILD_TOKEN_SEED: &[u8] = b"child_token";
+pub const TOKEN_PAYOUT_SEED: &[u8] = b"token_payout";
+pub const WSOL_TEMP_SEED: &[u8] = b"wsol_temp";
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+
+/// A locked deposit, payable once `locked_until` has passed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepositLock {
+    pub amount: u64,
+    pub locked_until: i64,
+}
+
+impl DepositLock {
+    pub const LEN: usize = 8 + 8;
+}
+
+/// Locks on a child balance, one per locked deposit while entries last. A
+/// deposit only shares an entry, and its expiry, once every entry is in use.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepositLocks {
+    pub entries: [DepositLock; MAX_DEPOSIT_LOCKS],
+}
+
+impl DepositLocks {
+    pub const LEN: usize = DepositLock::LEN * MAX_DEPOSIT_LOCKS;
+
+    /// Locks `amount` until `locked_until`, reusing an empty or expired entry.
+    /// Once every entry is in use, the amount joins the entry expiring soonest
+    /// at or after `locked_until`, or else the latest entry, whose expiry is
+    /// raised to `locked_until`. Adding never runs out of entries, so deposits
+    /// made for a child cannot block its later deposits.
+    pub fn add(&mut self, amount: u64, locked_until: i64, now: i64) -> Result<()> {
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.amount == 0 || now >= entry.locked_until)
+        {
+            *entry = DepositLock {
+                amount,
+                locked_until,
+            };
+            return Ok(());
+        }
+
+        let later = self
+            .entries
+            .iter_mut()
+            .filter(|entry| entry.locked_until >= locked_until)
+            .min_by_key(|entry| entry.locked_until);
+        let entry = match later {
+            Some(entry) => entry,
+            None => self
+                .entries
+                .iter_mut()
+                .max_by_key(|entry| entry.locked_until)
+                .ok_or(VaultError::MathOverflow)?,
+        };
+        entry.amount = entry
+            .amount
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        entry.locked_until = entry.locked_until.max(locked_until);
+        Ok(())
+    }
+
+    /// Total still locked at `now`.
+    pub fn locked(&self, now: i64) -> Result<u64> {
+        self.entries
+            .iter()
+            .filter(|entry| now < entry.locked_until)
+            .try_fold(0u64, |total, entry| total.checked_add(entry.amount))
+            .ok_or_else(|| VaultError::MathOverflow.into())
+    }
+}
+
+/// Deposited minus paid out, less what is still locked at `now`.
+fn unlocked_balance(
+    total_deposited: u64,
+    total_paid_out: u64,
+    locks: &DepositLocks,
+    now: i64,
+) -> Result<u64> {
+    let remaining = total_deposited
+        .checked_sub(total_paid_out)
+        .ok_or(VaultError::MathOverflow)?;
+    Ok(remaining.saturating_sub(locks.locked(now)?))
+}
 
 #[account]
+#[derive(Default)]
 pub struct ChildAccount {
     pub vault: Pubkey,           // 32
     pub authority: Pubkey,       // 32
@@ -57,10 +694,51 @@ pub struct ChildAccount {
     pub total_paid_out: u64,     // 8
     pub created_at: i64,         // 8
     pub bump: u8,                // 1
+
+    // Fields below were added after the original layout; `migrate_child`
+    // extends children created before them.
+    pub locks: DepositLocks,     // 128
+    pub frozen: bool,            // 1
+    pub freeze_reason: u8,       // 1
 }
 
 impl ChildAccount {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1; // 97
+    /// Size of a child account created before the lock and freeze fields.
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1; // 97
+    pub const LEN: usize = Self::LEGACY_LEN + DepositLocks::LEN + 1 + 1; // 227
+
+    /// Fills in a child account just created by `init_if_needed`, owned by
+    /// `authority`. Existing children are left untouched.
+    pub fn register(&mut self, vault: Pubkey, authority: Pubkey, now: i64, bump: u8) {
+        if self.vault != Pubkey::default() {
+            return;
+        }
+        self.vault = vault;
+        self.authority = authority;
+        self.total_deposited = 0;
+        self.total_paid_out = 0;
+        self.created_at = now;
+        self.bump = bump;
+        self.locks = DepositLocks::default();
+        self.frozen = false;
+        self.freeze_reason = 0;
+    }
+
+    /// Rejects deposits to a child the admin has frozen.
+    pub fn require_not_frozen(&self) -> Result<()> {
+        require!(!self.frozen, VaultError::ChildAccountFrozen);
+        Ok(())
+    }
+
+    /// Locks `amount` of newly deposited SOL until `locked_until`.
+    pub fn add_lock(&mut self, amount: u64, locked_until: i64, now: i64) -> Result<()> {
+        self.locks.add(amount, locked_until, now)
+    }
+
+    /// Deposited SOL not yet paid out and not locked at `now`.
+    pub fn unlocked_balance(&self, now: i64) -> Result<u64> {
+        unlocked_balance(self.total_deposited, self.total_paid_out, &self.locks, now)
+    }
 }
 
 #[account]
@@ -76,3 +754,661 @@ pub struct PendingPayout {
 impl PendingPayout {
     pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1; // 90
 }
+
+/// SPL mint children may deposit through `deposit_token`.
+#[account]
+pub struct DepositMint {
+    pub vault: Pubkey,           // 32
+    pub mint: Pubkey,            // 32
+    pub enabled: bool,           // 1
+    pub bump: u8,                // 1
+}
+
+impl DepositMint {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1; // 74
+}
+
+/// A child's deposits and payouts of one SPL mint.
+#[account]
+pub struct ChildTokenBalance {
+    pub vault: Pubkey,           // 32
+    pub child: Pubkey,           // 32
+    pub mint: Pubkey,            // 32
+    pub total_deposited: u64,    // 8
+    pub total_paid_out: u64,     // 8
+    pub bump: u8,                // 1
+    pub locks: DepositLocks,     // 128
+}
+
+impl ChildTokenBalance {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + DepositLocks::LEN; // 249
+
+    /// Locks `amount` of newly deposited tokens until `locked_until`.
+    pub fn add_lock(&mut self, amount: u64, locked_until: i64, now: i64) -> Result<()> {
+        self.locks.add(amount, locked_until, now)
+    }
+
+    /// Deposited tokens not yet paid out and not locked at `now`.
+    pub fn unlocked_balance(&self, now: i64) -> Result<u64> {
+        unlocked_balance(self.total_deposited, self.total_paid_out, &self.locks, now)
+    }
+}
+
+#[account]
+pub struct PendingTokenPayout {
+    pub vault: Pubkey,           // 32
+    pub child: Pubkey,           // 32
+    pub mint: Pubkey,            // 32
+    pub amount: u64,             // 8
+    pub requested_at: i64,       // 8
+    pub executed: bool,          // 1
+    pub bump: u8,                // 1
+}
+
+impl PendingTokenPayout {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + 1; // 122
+}
+
+#[account]
+pub struct PurchaseRecord {
+    pub vault: Pubkey,           // 32
+    pub user: Pubkey,            // 32
+    pub val_purchased: u64,      // 8
+    pub sol_spent: u64,          // 8
+    pub bump: u8,                // 1
+}
+
+impl PurchaseRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1; // 89
+}
+
+/// Whitelisted SPL mint accepted by `buy_val_with_token`, with its own rate in
+/// whole VAL per whole token (numerator / denominator).
+#[account]
+pub struct PaymentMint {
+    pub vault: Pubkey,                   // 32
+    pub mint: Pubkey,                    // 32
+    pub val_per_token_numerator: u64,    // 8
+    pub val_per_token_denominator: u64,  // 8
+    pub enabled: bool,                   // 1
+    pub total_received: u64,             // 8
+    pub bump: u8,                        // 1
+}
+
+impl PaymentMint {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + 8 + 1; // 98
+}
+
+#[account]
+pub struct VestingPosition {
+    pub vault: Pubkey,           // 32
+    pub beneficiary: Pubkey,     // 32
+    pub total_amount: u64,       // 8
+    pub claimed_amount: u64,     // 8
+    pub start_ts: i64,           // 8
+    pub cliff_ts: i64,           // 8
+    pub end_ts: i64,             // 8
+    pub vested_base: u64,        // 8
+    pub bump: u8,                // 1
+}
+
+impl VestingPosition {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1; // 121
+
+    /// Adds `amount` of purchased VAL at `now` and re-anchors the schedule to
+    /// start at `start_ts`. VAL already unlocked stays unlocked in
+    /// `vested_base`; everything still locked, plus `amount`, vests on the new
+    /// schedule.
+    pub fn add_purchase(
+        &mut self,
+        amount: u64,
+        start_ts: i64,
+        cliff: i64,
+        duration: i64,
+        now: i64,
+    ) -> Result<()> {
+        self.vested_base = self.unlocked_amount(now)?;
+        self.total_amount = self
+            .total_amount
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        self.start_ts = start_ts;
+        self.cliff_ts = start_ts.checked_add(cliff).ok_or(VaultError::MathOverflow)?;
+        self.end_ts = start_ts.checked_add(duration).ok_or(VaultError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// VAL unlocked at `now`: `vested_base`, plus the rest of the position
+    /// linearly from `start_ts` to `end_ts` once the cliff has passed.
+    pub fn unlocked_amount(&self, now: i64) -> Result<u64> {
+        if now < self.cliff_ts {
+            return Ok(self.vested_base);
+        }
+        if now >= self.end_ts {
+            return Ok(self.total_amount);
+        }
+
+        let vesting = self
+            .total_amount
+            .checked_sub(self.vested_base)
+            .ok_or(VaultError::MathOverflow)? as u128;
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        let unlocked = self.vested_base as u128 + vesting * elapsed / duration;
+        Ok(u64::try_from(unlocked).map_err(|_| VaultError::MathOverflow)?)
+    }
+
+    /// VAL unlocked at `now` that has not been claimed yet.
+    pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+        Ok(self
+            .unlocked_amount(now)?
+            .checked_sub(self.claimed_amount)
+            .ok_or(VaultError::MathOverflow)?)
+    }
+}
+
+/// Attestor-issued proof that `user` passed verification for a vault, valid until `expires_at`.
+#[account]
+pub struct Attestation {
+    pub vault: Pubkey,           // 32
+    pub user: Pubkey,            // 32
+    pub attestor: Pubkey,        // 32
+    pub issued_at: i64,          // 8
+    pub expires_at: i64,         // 8
+    pub bump: u8,                // 1
+}
+
+impl Attestation {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1; // 121
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault() -> Vault {
+        Vault {
+            val_per_sol_numerator: 1,
+            val_per_sol_denominator: 1,
+            max_supply: 1_000,
+            val_decimals: DEFAULT_VAL_DECIMALS,
+            reserved_supply: 100,
+            ..Vault::default()
+        }
+    }
+
+    #[test]
+    fn legacy_vault_prefix_survives_zero_extension() {
+        let mut v = vault();
+        v.admin_authority = Pubkey::new_unique();
+        v.treasury_bump = 254;
+        v.min_deposit = 5;
+
+        let mut data = Vec::new();
+        v.try_serialize(&mut data).unwrap();
+        data.truncate(Vault::LEGACY_LEN);
+        data.resize(Vault::LEN, 0);
+
+        let migrated = Vault::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(migrated.admin_authority, v.admin_authority);
+        assert_eq!(migrated.max_supply, v.max_supply);
+        assert_eq!(migrated.treasury_bump, 254);
+        assert_eq!(migrated.min_deposit, 0);
+    }
+
+    #[test]
+    fn admin_mints_count_against_reserved_and_max_supply() {
+        let mut v = vault();
+        v.record_admin_mint(60).unwrap();
+        assert!(v.record_admin_mint(41).is_err());
+        v.record_admin_mint(40).unwrap();
+        assert_eq!((v.reserved_minted, v.total_minted), (100, 100));
+
+        let mut v = vault();
+        v.total_minted = 950;
+        assert!(v.record_admin_mint(51).is_err());
+        assert_eq!(v.reserved_minted, 0);
+    }
+
+    #[test]
+    fn sales_leave_unminted_reserve_for_admin() {
+        let mut v = vault();
+        v.record_sale_mint(900).unwrap();
+        assert!(v.record_sale_mint(1).is_err());
+        assert_eq!(v.total_minted, 900);
+
+        v.record_admin_mint(40).unwrap();
+        assert!(v.record_sale_mint(1).is_err());
+        v.record_admin_mint(60).unwrap();
+        assert_eq!(v.total_minted, 1_000);
+    }
+
+    #[test]
+    fn reserved_supply_must_fit_next_to_sales() {
+        let mut v = vault();
+        v.record_sale_mint(850).unwrap();
+        v.set_reserved_supply(150).unwrap();
+        assert!(v.set_reserved_supply(151).is_err());
+
+        v.record_admin_mint(20).unwrap();
+        assert!(v.set_reserved_supply(19).is_err());
+        v.set_reserved_supply(20).unwrap();
+        assert_eq!(v.reserved_remaining(), 0);
+    }
+
+    #[test]
+    fn max_supply_decrease_clamps_unminted_reserve() {
+        let mut v = vault();
+        v.record_sale_mint(400).unwrap();
+        v.record_admin_mint(30).unwrap();
+        assert!(!v.decrease_max_supply(500).unwrap());
+        assert_eq!(v.reserved_supply, 100);
+
+        assert!(v.decrease_max_supply(450).unwrap());
+        assert_eq!(v.reserved_supply, 50);
+        assert!(v.decrease_max_supply(430).unwrap());
+        assert_eq!((v.reserved_supply, v.reserved_remaining()), (30, 0));
+        assert!(v.record_admin_mint(1).is_err());
+    }
+
+    #[test]
+    fn max_supply_decrease_allows_fractional_supply_down_to_minted() {
+        let mut v = vault();
+        v.max_supply = 10_000_000_000;
+        v.total_minted = 1_500_000_001;
+        v.reserved_supply = 0;
+        v.decrease_max_supply(1_500_000_001).unwrap();
+        assert_eq!(v.max_supply, 1_500_000_001);
+    }
+
+    #[test]
+    fn finalized_supply_rejects_admin_mints() {
+        let mut v = vault();
+        v.supply_finalized = true;
+        assert!(v.record_admin_mint(1).is_err());
+        assert_eq!(v.total_minted, 0);
+    }
+
+    #[test]
+    fn max_supply_decreases_immediately_but_not_below_minted() {
+        let mut v = vault();
+        v.val_decimals = 0;
+        v.total_minted = 400;
+        v.decrease_max_supply(500).unwrap();
+        assert_eq!(v.max_supply, 500);
+        assert!(v.decrease_max_supply(399).is_err());
+        assert!(v.decrease_max_supply(600).is_err());
+    }
+
+    #[test]
+    fn max_supply_increase_waits_for_delay() {
+        let mut v = vault();
+        v.val_decimals = 0;
+        v.queue_max_supply_increase(2_000, 100).unwrap();
+        assert!(v.apply_max_supply_increase(100 + MAX_SUPPLY_INCREASE_DELAY - 1).is_err());
+        v.apply_max_supply_increase(100 + MAX_SUPPLY_INCREASE_DELAY).unwrap();
+        assert_eq!((v.max_supply, v.pending_max_supply), (2_000, 0));
+        assert!(v.apply_max_supply_increase(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn decrease_cancels_queued_increase() {
+        let mut v = vault();
+        v.val_decimals = 0;
+        v.queue_max_supply_increase(2_000, 0).unwrap();
+        v.decrease_max_supply(900).unwrap();
+        assert!(v.apply_max_supply_increase(i64::MAX).is_err());
+        assert_eq!(v.max_supply, 900);
+    }
+
+    #[test]
+    fn rate_updates_respect_guardrails() {
+        let mut v = vault();
+        v.val_per_sol_numerator = 100;
+        v.rate_limits = RateLimits {
+            max_change_bps: 1_000,
+            min_update_interval: 60,
+            min_rate: 50 * pricing::RATE_SCALE as u64,
+            max_rate: 200 * pricing::RATE_SCALE as u64,
+        };
+
+        v.check_rate_update(110, 1, 0).unwrap();
+        v.check_rate_update(90, 1, 0).unwrap();
+        assert!(v.check_rate_update(111, 1, 0).is_err());
+        assert!(v.check_rate_update(89, 1, 0).is_err());
+
+        v.last_rate_update = 1_000;
+        assert!(v.check_rate_update(100, 1, 1_059).is_err());
+        v.check_rate_update(100, 1, 1_060).unwrap();
+
+        v.rate_limits.max_change_bps = 0;
+        v.check_rate_update(200, 1, 1_060).unwrap();
+        assert!(v.check_rate_update(201, 1, 1_060).is_err());
+        assert!(v.check_rate_update(49, 1, 1_060).is_err());
+    }
+
+    #[test]
+    fn scheduled_rate_applies_once_effective() {
+        let mut v = vault();
+        v.scheduled_numerator = 3;
+        v.scheduled_denominator = 2;
+        v.scheduled_rate_at = 500;
+
+        assert!(!v.apply_scheduled_rate(499));
+        assert_eq!(v.val_per_sol_numerator, 1);
+        assert!(v.apply_scheduled_rate(500));
+        assert_eq!((v.val_per_sol_numerator, v.val_per_sol_denominator), (3, 2));
+        assert_eq!((v.last_rate_update, v.scheduled_rate_at), (500, 0));
+        assert!(!v.apply_scheduled_rate(i64::MAX));
+    }
+
+    #[test]
+    fn pricing_changes_share_the_update_interval() {
+        let mut v = vault();
+        v.rate_limits.min_update_interval = 60;
+        v.record_pricing_change(1_000).unwrap();
+        assert!(v.record_pricing_change(1_059).is_err());
+        assert!(v.check_rate_update(1, 1, 1_059).is_err());
+        v.record_pricing_change(1_060).unwrap();
+        assert_eq!(v.last_rate_update, 1_060);
+    }
+
+    #[test]
+    fn curve_changes_respect_rate_limits() {
+        let mut v = vault();
+        v.rate_limits.max_change_bps = 1_000;
+        let linear = |start_price| PriceCurve::Linear { start_price, slope: 1, step: 1 };
+        v.check_price_curve(&linear(1_000_000_000)).unwrap();
+        assert!(v.check_price_curve(&linear(500_000_000)).is_err());
+        let exponential = PriceCurve::Exponential {
+            start_price: 2_000_000_000,
+            growth_bps: 1,
+            step: 1,
+        };
+        assert!(v.check_price_curve(&exponential).is_err());
+
+        v.rate_limits.max_change_bps = 0;
+        v.rate_limits.max_rate = 1_500_000_000;
+        assert!(v.check_price_curve(&linear(500_000_000)).is_err());
+        v.check_price_curve(&linear(800_000_000)).unwrap();
+
+        let oracle = PriceCurve::Oracle {
+            price_feed: Pubkey::new_unique(),
+            usd_price_per_val: 1,
+            max_price_age: 60,
+            max_confidence_bps: 100,
+        };
+        assert!(v.check_price_curve(&oracle).is_err());
+        v.rate_limits.max_rate = 0;
+        v.check_price_curve(&oracle).unwrap();
+    }
+
+    #[test]
+    fn tier_changes_respect_rate_limits() {
+        let tier = |supply_cap, val_per_sol_numerator| PriceTier {
+            supply_cap,
+            val_per_sol_numerator,
+            val_per_sol_denominator: 10,
+        };
+        let mut v = vault();
+        v.rate_limits.max_change_bps = 1_000;
+        v.rate_limits.min_rate = 950_000_000;
+        v.check_price_tiers(&[tier(100, 10), tier(200, 11)]).unwrap();
+        assert!(v.check_price_tiers(&[tier(100, 10), tier(200, 12)]).is_err());
+        assert!(v.check_price_tiers(&[tier(100, 9)]).is_err());
+
+        v.price_tiers[0] = tier(100, 12);
+        v.price_tier_count = 1;
+        assert!(v.check_price_curve(&PriceCurve::Tiered).is_err());
+    }
+
+    #[test]
+    fn rate_limits_apply_after_delay() {
+        let mut v = vault();
+        assert!(v.apply_rate_limits(0).is_err());
+        v.pending_rate_limits.max_change_bps = 500;
+        v.pending_rate_limits_at = 100;
+        assert!(v.apply_rate_limits(99).is_err());
+        v.apply_rate_limits(100).unwrap();
+        assert_eq!(v.rate_limits.max_change_bps, 500);
+        assert_eq!(v.pending_rate_limits_at, 0);
+    }
+
+    #[test]
+    fn deposits_respect_limits() {
+        let mut v = vault();
+        v.min_deposit = 10;
+        v.max_deposit_per_child = 100;
+        v.max_total_deposits = 150;
+
+        assert!(v.record_deposit(9, 9).is_err());
+        v.record_deposit(100, 100).unwrap();
+        assert!(v.record_deposit(10, 110).is_err());
+        v.record_deposit(50, 50).unwrap();
+        assert!(v.record_deposit(10, 10).is_err());
+        assert_eq!(v.total_deposited, 150);
+    }
+
+    #[test]
+    fn decimals_require_whole_max_supply() {
+        let mut v = vault();
+        v.max_supply = 5_000_000;
+        v.set_val_decimals(6).unwrap();
+        assert_eq!(v.val_decimals, 6);
+        assert!(v.set_val_decimals(7).is_err());
+        assert!(v.set_val_decimals(MAX_VAL_DECIMALS + 1).is_err());
+    }
+
+    #[test]
+    fn attestation_gate_requires_current_unexpired_attestation() {
+        let mut v = vault();
+        let user = Pubkey::new_unique();
+        assert!(v.require_attestation(&user, None, 0).is_ok());
+
+        v.attestor = Pubkey::new_unique();
+        assert!(v.require_attestation(&user, None, 0).is_err());
+
+        let attestation = Attestation {
+            vault: Pubkey::default(),
+            user,
+            attestor: v.attestor,
+            issued_at: 0,
+            expires_at: 100,
+            bump: 0,
+        };
+        assert!(v.require_attestation(&user, Some(&attestation), 99).is_ok());
+        assert!(v.require_attestation(&user, Some(&attestation), 100).is_err());
+        assert!(v
+            .require_attestation(&Pubkey::new_unique(), Some(&attestation), 0)
+            .is_err());
+
+        v.attestor = Pubkey::new_unique();
+        assert!(v.require_attestation(&user, Some(&attestation), 0).is_err());
+    }
+
+    fn child(total_deposited: u64) -> ChildAccount {
+        ChildAccount {
+            total_deposited,
+            ..ChildAccount::default()
+        }
+    }
+
+    #[test]
+    fn legacy_child_prefix_survives_zero_extension() {
+        let mut c = child(100);
+        c.authority = Pubkey::new_unique();
+        c.bump = 253;
+        c.add_lock(60, 1_000, 0).unwrap();
+        c.frozen = true;
+
+        let mut data = Vec::new();
+        c.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), ChildAccount::LEN);
+        data.truncate(ChildAccount::LEGACY_LEN);
+        data.resize(ChildAccount::LEN, 0);
+
+        let migrated = ChildAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(migrated.authority, c.authority);
+        assert_eq!(migrated.total_deposited, 100);
+        assert_eq!(migrated.bump, 253);
+        assert_eq!(migrated.locks, DepositLocks::default());
+        assert!(!migrated.frozen);
+    }
+
+    #[test]
+    fn frozen_children_reject_deposits() {
+        let mut c = child(0);
+        c.require_not_frozen().unwrap();
+        c.frozen = true;
+        c.freeze_reason = 3;
+        assert!(c.require_not_frozen().is_err());
+    }
+
+    #[test]
+    fn locked_deposits_are_not_payable_until_expiry() {
+        let mut c = child(100);
+        c.add_lock(60, 1_000, 0).unwrap();
+        assert_eq!(c.unlocked_balance(999).unwrap(), 40);
+        assert_eq!(c.unlocked_balance(1_000).unwrap(), 100);
+
+        c.total_paid_out = 40;
+        assert_eq!(c.unlocked_balance(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn each_deposit_keeps_its_own_lock() {
+        let mut c = child(100);
+        c.add_lock(30, 1_000, 0).unwrap();
+        c.add_lock(20, 2_000, 500).unwrap();
+        assert_eq!(c.unlocked_balance(999).unwrap(), 50);
+        // A later lock does not hold back the earlier deposit.
+        assert_eq!(c.unlocked_balance(1_000).unwrap(), 80);
+        assert_eq!(c.unlocked_balance(2_000).unwrap(), 100);
+    }
+
+    #[test]
+    fn expired_locks_free_their_entries() {
+        let mut c = child(100);
+        for i in 0..MAX_DEPOSIT_LOCKS as i64 {
+            c.add_lock(1, 1_000 + i, 0).unwrap();
+        }
+        c.add_lock(1, 2_000, 1_000).unwrap();
+        assert_eq!(c.locks.locked(1_000).unwrap(), MAX_DEPOSIT_LOCKS as u64);
+        assert!(c.locks.entries.contains(&DepositLock {
+            amount: 1,
+            locked_until: 2_000
+        }));
+    }
+
+    #[test]
+    fn full_locks_merge_instead_of_failing() {
+        let mut c = child(100);
+        for i in 0..MAX_DEPOSIT_LOCKS as i64 {
+            c.add_lock(1, 1_000 + i, 0).unwrap();
+        }
+
+        // Joins the entry expiring soonest after it, keeping that expiry.
+        c.add_lock(5, 1_003, 500).unwrap();
+        assert_eq!(c.unlocked_balance(999).unwrap(), 87);
+        assert_eq!(c.unlocked_balance(1_003).unwrap(), 96);
+
+        // Nothing expires later, so the latest entry is extended; earlier
+        // entries keep their expiry.
+        c.add_lock(7, 5_000, 600).unwrap();
+        assert_eq!(c.unlocked_balance(1_006).unwrap(), 92);
+        assert_eq!(c.unlocked_balance(1_007).unwrap(), 92);
+        assert_eq!(c.unlocked_balance(5_000).unwrap(), 100);
+
+        for _ in 0..100 {
+            c.add_lock(1, 6_000, 700).unwrap();
+        }
+    }
+
+    #[test]
+    fn token_balances_lock_deposits() {
+        let mut balance = ChildTokenBalance {
+            vault: Pubkey::default(),
+            child: Pubkey::default(),
+            mint: Pubkey::default(),
+            total_deposited: 100,
+            total_paid_out: 0,
+            bump: 0,
+            locks: DepositLocks::default(),
+        };
+        balance.add_lock(60, 1_000, 0).unwrap();
+        assert_eq!(balance.unlocked_balance(999).unwrap(), 40);
+        assert_eq!(balance.unlocked_balance(1_000).unwrap(), 100);
+    }
+
+    #[test]
+    fn vault_minimum_lock_applies_to_every_deposit() {
+        let mut v = vault();
+        assert_eq!(v.deposit_locked_until(0, 100).unwrap(), 0);
+        assert_eq!(v.deposit_locked_until(50, 100).unwrap(), 150);
+        v.min_lock_duration = 200;
+        assert_eq!(v.deposit_locked_until(0, 100).unwrap(), 300);
+        assert_eq!(v.deposit_locked_until(500, 100).unwrap(), 600);
+        assert!(v.deposit_locked_until(-1, 100).is_err());
+    }
+
+    fn position(total_amount: u64) -> VestingPosition {
+        VestingPosition {
+            vault: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            total_amount,
+            claimed_amount: 0,
+            start_ts: 1_000,
+            cliff_ts: 1_100,
+            end_ts: 2_000,
+            vested_base: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn purchase_after_end_vests_from_purchase_time() {
+        let mut p = position(1_000);
+        p.claimed_amount = 1_000;
+        p.add_purchase(500, 3_000, 100, 1_000, 3_000).unwrap();
+
+        assert_eq!(p.claimable_amount(3_000).unwrap(), 0);
+        assert_eq!(p.claimable_amount(3_099).unwrap(), 0);
+        assert_eq!(p.claimable_amount(3_100).unwrap(), 50);
+        assert_eq!(p.claimable_amount(4_000).unwrap(), 500);
+    }
+
+    #[test]
+    fn top_up_keeps_unlocked_and_revests_the_rest() {
+        let mut p = position(1_000);
+        p.add_purchase(1_000, 1_500, 100, 1_000, 1_500).unwrap();
+
+        assert_eq!(p.vested_base, 500);
+        assert_eq!(p.unlocked_amount(1_550).unwrap(), 500);
+        assert_eq!(p.unlocked_amount(2_000).unwrap(), 1_250);
+        assert_eq!(p.unlocked_amount(2_500).unwrap(), 2_000);
+    }
+
+    #[test]
+    fn nothing_unlocks_before_cliff() {
+        let p = position(1_000);
+        assert_eq!(p.unlocked_amount(999).unwrap(), 0);
+        assert_eq!(p.unlocked_amount(1_099).unwrap(), 0);
+    }
+
+    #[test]
+    fn unlocks_linearly_from_start_after_cliff() {
+        let p = position(1_000);
+        assert_eq!(p.unlocked_amount(1_100).unwrap(), 100);
+        assert_eq!(p.unlocked_amount(1_500).unwrap(), 500);
+        assert_eq!(p.unlocked_amount(2_000).unwrap(), 1_000);
+        assert_eq!(p.unlocked_amount(i64::MAX).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn claimable_excludes_claimed() {
+        let mut p = position(1_000);
+        p.claimed_amount = 400;
+        assert_eq!(p.claimable_amount(1_500).unwrap(), 100);
+        assert_eq!(p.claimable_amount(2_000).unwrap(), 600);
+    }
+}
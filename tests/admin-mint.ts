@@ -0,0 +1,75 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import { TestVault, createTestVault, expectError } from "./helpers";
+
+describe("admin_mint_val_batch", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+  const connection = provider.connection;
+
+  let tv: TestVault;
+  let recipients: PublicKey[];
+  let atas: PublicKey[];
+
+  function mintBatch(to: PublicKey[], destinations: PublicKey[], amounts: number[]) {
+    return program.methods
+      .adminMintValBatch(
+        to,
+        amounts.map((amount) => new anchor.BN(amount))
+      )
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        valMint: tv.valMint,
+        mintAuthority: tv.mintAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        destinations.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      )
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+    await program.methods
+      .setReservedSupply(new anchor.BN(1_000).mul(new anchor.BN(LAMPORTS_PER_SOL)))
+      .accountsPartial({ admin: tv.admin.publicKey, vault: tv.vault })
+      .signers([tv.admin])
+      .rpc();
+
+    recipients = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    atas = [];
+    for (const recipient of recipients) {
+      const ata = await getOrCreateAssociatedTokenAccount(connection, tv.admin, tv.valMint, recipient);
+      atas.push(ata.address);
+    }
+  });
+
+  it("mints to each recipient's ATA", async () => {
+    await mintBatch(recipients, atas, [LAMPORTS_PER_SOL, 2 * LAMPORTS_PER_SOL]);
+
+    expect(Number((await getAccount(connection, atas[0])).amount)).to.equal(LAMPORTS_PER_SOL);
+    expect(Number((await getAccount(connection, atas[1])).amount)).to.equal(2 * LAMPORTS_PER_SOL);
+  });
+
+  it("rejects a destination that is not the recipient's ATA", async () => {
+    const other = await createAccount(connection, tv.admin, tv.valMint, recipients[0], Keypair.generate());
+    await expectError(() => mintBatch([recipients[0]], [other], [LAMPORTS_PER_SOL]), "InvalidRecipients");
+  });
+
+  it("rejects another recipient's ATA", async () => {
+    await expectError(() => mintBatch([recipients[0]], [atas[1]], [LAMPORTS_PER_SOL]), "InvalidRecipients");
+  });
+});
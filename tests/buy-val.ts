@@ -0,0 +1,68 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { TestVault, createTestVault, expectError, pda } from "./helpers";
+
+describe("buy_val", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+  const connection = provider.connection;
+
+  let tv: TestVault;
+
+  function buy(lamports: number) {
+    const user = tv.admin.publicKey;
+    return program.methods
+      .buyVal(new anchor.BN(lamports))
+      .accountsPartial({
+        user,
+        vault: tv.vault,
+        treasury: tv.treasury,
+        valMint: tv.valMint,
+        mintAuthority: tv.mintAuthority,
+        userValAta: getAssociatedTokenAddressSync(tv.valMint, user),
+        purchaseRecord: pda(program, [Buffer.from("purchase"), tv.vault.toBuffer(), user.toBuffer()]),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+  });
+
+  it("mints VAL at the fixed rate", async () => {
+    await buy(0.1 * LAMPORTS_PER_SOL);
+
+    const ata = await getAccount(
+      connection,
+      getAssociatedTokenAddressSync(tv.valMint, tv.admin.publicKey)
+    );
+    expect(Number(ata.amount)).to.equal(10 * LAMPORTS_PER_SOL);
+  });
+
+  it("rejects a purchase that rounds down to zero VAL", async () => {
+    // Half a VAL per SOL: one lamport buys half of the smallest VAL unit.
+    await program.methods
+      .updateExchangeRate(new anchor.BN(1), new anchor.BN(2))
+      .accountsPartial({ admin: tv.admin.publicKey, vault: tv.vault })
+      .signers([tv.admin])
+      .rpc();
+
+    const treasuryBefore = await connection.getBalance(tv.treasury);
+    await expectError(() => buy(1), "ZeroValOutput");
+    expect(await connection.getBalance(tv.treasury)).to.equal(treasuryBefore);
+  });
+});
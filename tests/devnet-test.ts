@@ -90,7 +90,7 @@ async function main() {
   console.log("\n--- Test 2: Initialize VAL Mint ---");
   try {
     const tx = await program.methods
-      .initializeValMint()
+      .initializeValMint(9)
       .accounts({
         admin,
         vault,
@@ -89,7 +89,7 @@ async function main() {
 
   // Initialize mint
   await program.methods
-    .initializeValMint()
+    .initializeValMint(9)
     .accounts({
       admin,
       vault,
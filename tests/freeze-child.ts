@@ -0,0 +1,229 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import {
+  TestVault,
+  airdropSol,
+  createTestVault,
+  expectError,
+  fundTokenAccount,
+  pda,
+  u64Seed,
+} from "./helpers";
+
+describe("freeze_child", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+  const connection = provider.connection;
+
+  let tv: TestVault;
+  let depositor: Keypair;
+  let child: PublicKey;
+  let mint: PublicKey;
+
+  function deposit(lamports: number) {
+    return program.methods
+      .depositAndAutoRegister(new anchor.BN(lamports))
+      .accountsPartial({
+        depositor: depositor.publicKey,
+        vault: tv.vault,
+        child,
+        treasury: tv.treasury,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([depositor])
+      .rpc();
+  }
+
+  function payoutPda(nonce: number) {
+    return pda(program, [Buffer.from("payout"), tv.vault.toBuffer(), child.toBuffer(), u64Seed(nonce)]);
+  }
+
+  function requestPayout(lamports: number, nonce: number) {
+    return program.methods
+      .adminRequestPayout(new anchor.BN(lamports), new anchor.BN(nonce))
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        child,
+        payout: payoutPda(nonce),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function executePayout(nonce: number) {
+    return program.methods
+      .adminExecutePayout()
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        child,
+        payout: payoutPda(nonce),
+        treasury: tv.treasury,
+        recipient: depositor.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function childTokenBalance() {
+    return pda(program, [Buffer.from("child_token"), child.toBuffer(), mint.toBuffer()]);
+  }
+
+  function treasuryAta() {
+    return getAssociatedTokenAddressSync(mint, tv.treasury, true);
+  }
+
+  function tokenPayoutPda(nonce: number) {
+    return pda(program, [
+      Buffer.from("token_payout"),
+      tv.vault.toBuffer(),
+      child.toBuffer(),
+      mint.toBuffer(),
+      u64Seed(nonce),
+    ]);
+  }
+
+  /** Deposits `amount` of `mint` and requests a token payout of half of it. */
+  async function depositTokenAndRequestPayout(amount: number) {
+    const depositMint = pda(program, [Buffer.from("deposit_mint"), tv.vault.toBuffer(), mint.toBuffer()]);
+    await program.methods
+      .setDepositMint(true)
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        mint,
+        depositMint,
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+    await program.methods
+      .depositToken(new anchor.BN(amount), new anchor.BN(0))
+      .accountsPartial({
+        depositor: depositor.publicKey,
+        vault: tv.vault,
+        child,
+        mint,
+        depositMint,
+        childTokenBalance: childTokenBalance(),
+        depositorTokenAccount: getAssociatedTokenAddressSync(mint, depositor.publicKey),
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([depositor])
+      .rpc();
+    await program.methods
+      .adminRequestTokenPayout(new anchor.BN(amount / 2), new anchor.BN(0))
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        child,
+        childTokenBalance: childTokenBalance(),
+        payout: tokenPayoutPda(0),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function executeTokenPayout(nonce: number) {
+    return program.methods
+      .adminExecuteTokenPayout()
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        child,
+        childTokenBalance: childTokenBalance(),
+        payout: tokenPayoutPda(nonce),
+        mint,
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(),
+        recipient: depositor.publicKey,
+        recipientTokenAta: getAssociatedTokenAddressSync(mint, depositor.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+    depositor = Keypair.generate();
+    await airdropSol(connection, depositor.publicKey, 10 * LAMPORTS_PER_SOL);
+    child = pda(program, [Buffer.from("child"), tv.vault.toBuffer(), depositor.publicKey.toBuffer()]);
+    await deposit(LAMPORTS_PER_SOL);
+
+    // Payouts requested before the freeze must still be held by it.
+    await requestPayout(LAMPORTS_PER_SOL / 4, 0);
+    mint = await createMint(connection, tv.admin, tv.admin.publicKey, null, 6);
+    await fundTokenAccount(connection, tv.admin, mint, depositor.publicKey, 10_000_000);
+    await depositTokenAndRequestPayout(10_000_000);
+  });
+
+  it("freezes a child with a reason code", async () => {
+    await program.methods
+      .freezeChild(7)
+      .accountsPartial({ admin: tv.admin.publicKey, vault: tv.vault, child })
+      .signers([tv.admin])
+      .rpc();
+
+    const account = await program.account.childAccount.fetch(child);
+    expect(account.frozen).to.be.true;
+    expect(account.freezeReason).to.equal(7);
+  });
+
+  it("rejects deposits to a frozen child", async () => {
+    await expectError(() => deposit(LAMPORTS_PER_SOL), "ChildAccountFrozen");
+  });
+
+  it("rejects payout requests for a frozen child", async () => {
+    await expectError(() => requestPayout(LAMPORTS_PER_SOL / 2, 1), "ChildAccountFrozen");
+  });
+
+  it("rejects executing a payout requested before the freeze", async () => {
+    await expectError(() => executePayout(0), "ChildAccountFrozen");
+    const payout = await program.account.pendingPayout.fetch(payoutPda(0));
+    expect(payout.executed).to.be.false;
+  });
+
+  it("rejects executing a token payout requested before the freeze", async () => {
+    await expectError(() => executeTokenPayout(0), "ChildAccountFrozen");
+    const balance = await program.account.childTokenBalance.fetch(childTokenBalance());
+    expect(balance.totalPaidOut.toNumber()).to.equal(0);
+  });
+
+  it("accepts deposits again once unfrozen", async () => {
+    await program.methods
+      .unfreezeChild()
+      .accountsPartial({ admin: tv.admin.publicKey, vault: tv.vault, child })
+      .signers([tv.admin])
+      .rpc();
+    await deposit(LAMPORTS_PER_SOL);
+
+    const account = await program.account.childAccount.fetch(child);
+    expect(account.frozen).to.be.false;
+    expect(account.totalDeposited.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+  });
+});
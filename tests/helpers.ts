@@ -0,0 +1,169 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+
+/** A freshly initialized vault owned by its own admin keypair. */
+export interface TestVault {
+  admin: Keypair;
+  vault: PublicKey;
+  treasury: PublicKey;
+  valMint: PublicKey;
+  mintAuthority: PublicKey;
+}
+
+/** Airdrop helper using blockhash-based confirmation. */
+export async function airdropSol(
+  connection: anchor.web3.Connection,
+  to: PublicKey,
+  lamports: number
+) {
+  const bh = await connection.getLatestBlockhash();
+  const sig = await connection.requestAirdrop(to, lamports);
+  await connection.confirmTransaction(
+    { signature: sig, blockhash: bh.blockhash, lastValidBlockHeight: bh.lastValidBlockHeight },
+    "confirmed"
+  );
+}
+
+export function pda(program: Program<VaultProject>, seeds: (Buffer | Uint8Array)[]): PublicKey {
+  return PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+}
+
+export function u64Seed(value: number): Buffer {
+  const buffer = Buffer.alloc(8);
+  buffer.writeBigUInt64LE(BigInt(value));
+  return buffer;
+}
+
+/** Creates a vault selling `valPerSol` whole VAL per whole SOL, with a 9-decimal VAL mint. */
+export async function createTestVault(
+  program: Program<VaultProject>,
+  valPerSol = 100,
+  maxSupplyVal = 1_000_000
+): Promise<TestVault> {
+  const connection = program.provider.connection;
+  const admin = Keypair.generate();
+  await airdropSol(connection, admin.publicKey, 100 * LAMPORTS_PER_SOL);
+
+  const vault = pda(program, [Buffer.from("vault"), admin.publicKey.toBuffer()]);
+  const treasury = pda(program, [Buffer.from("treasury"), vault.toBuffer()]);
+  const valMint = pda(program, [Buffer.from("val_mint"), vault.toBuffer()]);
+  const mintAuthority = pda(program, [Buffer.from("mint_authority"), vault.toBuffer()]);
+
+  await program.methods
+    .initialize(
+      new anchor.BN(valPerSol),
+      new anchor.BN(1),
+      new anchor.BN(maxSupplyVal).mul(new anchor.BN(LAMPORTS_PER_SOL))
+    )
+    .accountsPartial({
+      admin: admin.publicKey,
+      adminDestination: admin.publicKey,
+      vault,
+      treasury,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([admin])
+    .rpc();
+
+  await program.methods
+    .initializeValMint(9)
+    .accountsPartial({
+      admin: admin.publicKey,
+      vault,
+      valMint,
+      mintAuthority,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([admin])
+    .rpc();
+
+  return { admin, vault, treasury, valMint, mintAuthority };
+}
+
+/** Creates a Token-2022 mint that withholds `feeBps` of every transfer, with `payer` as its authorities. */
+export async function createTransferFeeMint(
+  connection: anchor.web3.Connection,
+  payer: Keypair,
+  decimals: number,
+  feeBps: number
+): Promise<PublicKey> {
+  const mint = Keypair.generate();
+  const space = getMintLen([ExtensionType.TransferFeeConfig]);
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space,
+      lamports: await connection.getMinimumBalanceForRentExemption(space),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    createInitializeTransferFeeConfigInstruction(
+      mint.publicKey,
+      payer.publicKey,
+      payer.publicKey,
+      feeBps,
+      BigInt(Number.MAX_SAFE_INTEGER),
+      TOKEN_2022_PROGRAM_ID
+    ),
+    createInitializeMintInstruction(mint.publicKey, decimals, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+  );
+  await sendAndConfirmTransaction(connection, tx, [payer, mint]);
+  return mint.publicKey;
+}
+
+/** Mints `amount` of `mint` (authority `payer`) to `owner`'s ATA and returns the ATA. */
+export async function fundTokenAccount(
+  connection: anchor.web3.Connection,
+  payer: Keypair,
+  mint: PublicKey,
+  owner: PublicKey,
+  amount: number,
+  tokenProgram = TOKEN_PROGRAM_ID
+): Promise<PublicKey> {
+  const ata = await getOrCreateAssociatedTokenAccount(
+    connection,
+    payer,
+    mint,
+    owner,
+    true,
+    undefined,
+    undefined,
+    tokenProgram
+  );
+  await mintTo(connection, payer, mint, ata.address, payer, amount, [], undefined, tokenProgram);
+  return ata.address;
+}
+
+/** Runs `fn` and asserts it fails with the program error `code`. */
+export async function expectError(fn: () => Promise<unknown>, code: string) {
+  try {
+    await fn();
+  } catch (err) {
+    const actual = (err as anchor.AnchorError).error?.errorCode?.code;
+    if (actual !== code) {
+      throw new Error(`expected ${code}, got ${actual ?? err}`);
+    }
+    return;
+  }
+  throw new Error(`expected ${code}, but the transaction succeeded`);
+}
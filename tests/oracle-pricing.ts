@@ -0,0 +1,93 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { TestVault, createTestVault, expectError, pda } from "./helpers";
+
+// Mock Pyth SOL/USD feed loaded by Anchor.toml: $150 +/- $0.05, published 2025-01-01.
+const PRICE_FEED = new PublicKey("EpRXRuzoiWwd7sK7UocdW6xiCM8Lac2GSvdDDA18B71C");
+const FEED_PUBLISH_TIME = 1_735_689_600;
+
+describe("oracle pricing", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+
+  let tv: TestVault;
+
+  /** Staleness window that accepts the fixture's fixed publish time, plus an hour. */
+  async function fixtureAge(): Promise<number> {
+    const slot = await provider.connection.getSlot();
+    const now = await provider.connection.getBlockTime(slot);
+    return now - FEED_PUBLISH_TIME + 3600;
+  }
+
+  async function setOracleCurve(maxPriceAge: number, priceFeed = PRICE_FEED) {
+    await program.methods
+      .updatePriceCurve({
+        oracle: {
+          priceFeed,
+          usdPricePerVal: new anchor.BN(1_500_000), // $1.50 per VAL
+          maxPriceAge,
+          maxConfidenceBps: 100,
+        },
+      })
+      .accountsPartial({ admin: tv.admin.publicKey, vault: tv.vault })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function buy(lamports: number, priceFeed: PublicKey = PRICE_FEED) {
+    const user = tv.admin.publicKey;
+    return program.methods
+      .buyVal(new anchor.BN(lamports))
+      .accountsPartial({
+        user,
+        vault: tv.vault,
+        treasury: tv.treasury,
+        valMint: tv.valMint,
+        mintAuthority: tv.mintAuthority,
+        userValAta: getAssociatedTokenAddressSync(tv.valMint, user),
+        purchaseRecord: pda(program, [Buffer.from("purchase"), tv.vault.toBuffer(), user.toBuffer()]),
+        priceFeed,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+  });
+
+  it("prices buy_val at the feed's SOL/USD price", async () => {
+    await setOracleCurve(await fixtureAge());
+    await buy(0.1 * LAMPORTS_PER_SOL);
+
+    // 0.1 SOL at $150 = $15, at $1.50 per VAL = 10 VAL.
+    const ata = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(tv.valMint, tv.admin.publicKey)
+    );
+    expect(Number(ata.amount)).to.equal(10 * LAMPORTS_PER_SOL);
+  });
+
+  it("rejects a stale feed", async () => {
+    await setOracleCurve(60);
+    await expectError(() => buy(0.1 * LAMPORTS_PER_SOL), "StaleOraclePrice");
+  });
+
+  it("rejects a feed other than the configured one", async () => {
+    await setOracleCurve(await fixtureAge());
+    await expectError(() => buy(0.1 * LAMPORTS_PER_SOL, tv.treasury), "InvalidPriceFeed");
+  });
+});
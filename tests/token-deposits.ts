@@ -0,0 +1,232 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import {
+  TestVault,
+  airdropSol,
+  createTestVault,
+  createTransferFeeMint,
+  expectError,
+  fundTokenAccount,
+  pda,
+  u64Seed,
+} from "./helpers";
+
+const TOKEN = 1_000_000; // one whole deposit token at 6 decimals
+
+describe("token deposits and payouts", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+  const connection = provider.connection;
+
+  let tv: TestVault;
+  let depositor: Keypair;
+  let child: PublicKey;
+
+  function childTokenBalance(mint: PublicKey) {
+    return pda(program, [Buffer.from("child_token"), child.toBuffer(), mint.toBuffer()]);
+  }
+
+  function treasuryAta(mint: PublicKey, tokenProgram = TOKEN_PROGRAM_ID) {
+    return getAssociatedTokenAddressSync(mint, tv.treasury, true, tokenProgram);
+  }
+
+  function setDepositMint(mint: PublicKey, enabled: boolean, tokenProgram = TOKEN_PROGRAM_ID) {
+    return program.methods
+      .setDepositMint(enabled)
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        mint,
+        depositMint: pda(program, [Buffer.from("deposit_mint"), tv.vault.toBuffer(), mint.toBuffer()]),
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(mint, tokenProgram),
+        tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function depositToken(
+    mint: PublicKey,
+    amount: number,
+    tokenProgram = TOKEN_PROGRAM_ID,
+    lockDuration = 0
+  ) {
+    return program.methods
+      .depositToken(new anchor.BN(amount), new anchor.BN(lockDuration))
+      .accountsPartial({
+        depositor: depositor.publicKey,
+        vault: tv.vault,
+        child,
+        mint,
+        depositMint: pda(program, [Buffer.from("deposit_mint"), tv.vault.toBuffer(), mint.toBuffer()]),
+        childTokenBalance: childTokenBalance(mint),
+        depositorTokenAccount: getAssociatedTokenAddressSync(
+          mint,
+          depositor.publicKey,
+          false,
+          tokenProgram
+        ),
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(mint, tokenProgram),
+        tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([depositor])
+      .rpc();
+  }
+
+  function payoutPda(mint: PublicKey, nonce: number) {
+    return pda(program, [
+      Buffer.from("token_payout"),
+      tv.vault.toBuffer(),
+      child.toBuffer(),
+      mint.toBuffer(),
+      u64Seed(nonce),
+    ]);
+  }
+
+  function requestPayout(mint: PublicKey, amount: number, nonce: number) {
+    return program.methods
+      .adminRequestTokenPayout(new anchor.BN(amount), new anchor.BN(nonce))
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        child,
+        childTokenBalance: childTokenBalance(mint),
+        payout: payoutPda(mint, nonce),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function executePayout(mint: PublicKey, nonce: number) {
+    return program.methods
+      .adminExecuteTokenPayout()
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        child,
+        childTokenBalance: childTokenBalance(mint),
+        payout: payoutPda(mint, nonce),
+        mint,
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(mint),
+        recipient: depositor.publicKey,
+        recipientTokenAta: getAssociatedTokenAddressSync(mint, depositor.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+    depositor = Keypair.generate();
+    await airdropSol(connection, depositor.publicKey, 10 * LAMPORTS_PER_SOL);
+    child = pda(program, [Buffer.from("child"), tv.vault.toBuffer(), depositor.publicKey.toBuffer()]);
+  });
+
+  describe("deposit_token and token payouts", () => {
+    let usdc: PublicKey;
+
+    before(async () => {
+      usdc = await createMint(connection, tv.admin, tv.admin.publicKey, null, 6);
+      await fundTokenAccount(connection, tv.admin, usdc, depositor.publicKey, 100 * TOKEN);
+      await setDepositMint(usdc, true);
+    });
+
+    it("credits the child's per-mint balance", async () => {
+      await depositToken(usdc, 10 * TOKEN);
+
+      const balance = await program.account.childTokenBalance.fetch(childTokenBalance(usdc));
+      expect(balance.totalDeposited.toNumber()).to.equal(10 * TOKEN);
+      const treasury = await getAccount(connection, treasuryAta(usdc));
+      expect(Number(treasury.amount)).to.equal(10 * TOKEN);
+    });
+
+    it("pays out a requested amount to the child's authority", async () => {
+      await requestPayout(usdc, 4 * TOKEN, 0);
+      await executePayout(usdc, 0);
+
+      const balance = await program.account.childTokenBalance.fetch(childTokenBalance(usdc));
+      expect(balance.totalPaidOut.toNumber()).to.equal(4 * TOKEN);
+      const recipient = await getAccount(
+        connection,
+        getAssociatedTokenAddressSync(usdc, depositor.publicKey)
+      );
+      expect(Number(recipient.amount)).to.equal(94 * TOKEN);
+    });
+
+    it("rejects a payout above the child's remaining balance", async () => {
+      await expectError(() => requestPayout(usdc, 7 * TOKEN, 1), "ExceedsAllowedPayout");
+    });
+
+    it("rejects executing a payout twice", async () => {
+      await expectError(() => executePayout(usdc, 0), "AlreadyExecuted");
+    });
+
+    it("holds a locked deposit back from payouts", async () => {
+      await depositToken(usdc, 2 * TOKEN, TOKEN_PROGRAM_ID, 3600);
+
+      const balance = await program.account.childTokenBalance.fetch(childTokenBalance(usdc));
+      expect(balance.totalDeposited.toNumber()).to.equal(12 * TOKEN);
+      await expectError(() => requestPayout(usdc, 7 * TOKEN, 1), "FundsLocked");
+      await requestPayout(usdc, 6 * TOKEN, 1);
+    });
+
+    it("rejects a disabled deposit mint", async () => {
+      await setDepositMint(usdc, false);
+      await expectError(() => depositToken(usdc, TOKEN), "DepositMintDisabled");
+    });
+  });
+
+  describe("transfer fee mints", () => {
+    let feeMint: PublicKey;
+
+    before(async () => {
+      feeMint = await createTransferFeeMint(connection, tv.admin, 6, 100); // 1% fee
+      await fundTokenAccount(
+        connection,
+        tv.admin,
+        feeMint,
+        depositor.publicKey,
+        100 * TOKEN,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await setDepositMint(feeMint, true, TOKEN_2022_PROGRAM_ID);
+    });
+
+    it("credits only what the treasury received after the fee", async () => {
+      await depositToken(feeMint, 10 * TOKEN, TOKEN_2022_PROGRAM_ID);
+
+      const balance = await program.account.childTokenBalance.fetch(childTokenBalance(feeMint));
+      expect(balance.totalDeposited.toNumber()).to.equal(9.9 * TOKEN);
+      const treasury = await getAccount(
+        connection,
+        treasuryAta(feeMint, TOKEN_2022_PROGRAM_ID),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(treasury.amount)).to.equal(9.9 * TOKEN);
+    });
+  });
+});
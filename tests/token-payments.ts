@@ -0,0 +1,202 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import {
+  TestVault,
+  airdropSol,
+  createTestVault,
+  createTransferFeeMint,
+  expectError,
+  fundTokenAccount,
+  pda,
+} from "./helpers";
+
+const TOKEN = 1_000_000; // one whole payment token at 6 decimals
+
+describe("token payments", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+  const connection = provider.connection;
+
+  let tv: TestVault;
+  let buyer: Keypair;
+
+  function paymentMintPda(mint: PublicKey) {
+    return pda(program, [Buffer.from("payment_mint"), tv.vault.toBuffer(), mint.toBuffer()]);
+  }
+
+  function treasuryAta(mint: PublicKey, tokenProgram = TOKEN_PROGRAM_ID) {
+    return getAssociatedTokenAddressSync(mint, tv.treasury, true, tokenProgram);
+  }
+
+  /** Whitelists `mint` at `valPerToken / denominator` whole VAL per whole token. */
+  function setPaymentMint(
+    mint: PublicKey,
+    valPerToken: number,
+    enabled: boolean,
+    tokenProgram = TOKEN_PROGRAM_ID,
+    denominator = 1
+  ) {
+    return program.methods
+      .setPaymentMint(new anchor.BN(valPerToken), new anchor.BN(denominator), enabled)
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        mint,
+        paymentMint: paymentMintPda(mint),
+        treasury: tv.treasury,
+        treasuryTokenAta: treasuryAta(mint, tokenProgram),
+        tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  function buyWithToken(mint: PublicKey, amount: number, tokenProgram = TOKEN_PROGRAM_ID) {
+    return program.methods
+      .buyValWithToken(new anchor.BN(amount))
+      .accountsPartial({
+        user: buyer.publicKey,
+        vault: tv.vault,
+        treasury: tv.treasury,
+        paymentTokenMint: mint,
+        paymentMint: paymentMintPda(mint),
+        userTokenAccount: getAssociatedTokenAddressSync(mint, buyer.publicKey, false, tokenProgram),
+        treasuryTokenAta: treasuryAta(mint, tokenProgram),
+        valMint: tv.valMint,
+        mintAuthority: tv.mintAuthority,
+        userValAta: getAssociatedTokenAddressSync(tv.valMint, buyer.publicKey),
+        purchaseRecord: pda(program, [
+          Buffer.from("purchase"),
+          tv.vault.toBuffer(),
+          buyer.publicKey.toBuffer(),
+        ]),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: tokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  function withdrawToken(mint: PublicKey, amount: number, admin = tv.admin) {
+    return program.methods
+      .adminWithdrawToken(new anchor.BN(amount))
+      .accountsPartial({
+        admin: admin.publicKey,
+        vault: tv.vault,
+        treasury: tv.treasury,
+        mint,
+        treasuryTokenAta: treasuryAta(mint),
+        adminDestination: tv.admin.publicKey,
+        destinationTokenAta: getAssociatedTokenAddressSync(mint, tv.admin.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+  }
+
+  async function valBalance(owner: PublicKey) {
+    const ata = await getAccount(connection, getAssociatedTokenAddressSync(tv.valMint, owner));
+    return Number(ata.amount);
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+    buyer = Keypair.generate();
+    await airdropSol(connection, buyer.publicKey, 10 * LAMPORTS_PER_SOL);
+  });
+
+  describe("buy_val_with_token and admin_withdraw_token", () => {
+    let usdc: PublicKey;
+
+    before(async () => {
+      usdc = await createMint(connection, tv.admin, tv.admin.publicKey, null, 6);
+      await fundTokenAccount(connection, tv.admin, usdc, buyer.publicKey, 100 * TOKEN);
+      await setPaymentMint(usdc, 2, true);
+    });
+
+    it("mints VAL at the payment mint's rate into the treasury's token account", async () => {
+      await buyWithToken(usdc, 10 * TOKEN);
+
+      expect(await valBalance(buyer.publicKey)).to.equal(20 * LAMPORTS_PER_SOL);
+      const treasury = await getAccount(connection, treasuryAta(usdc));
+      expect(Number(treasury.amount)).to.equal(10 * TOKEN);
+      const record = await program.account.paymentMint.fetch(paymentMintPda(usdc));
+      expect(record.totalReceived.toNumber()).to.equal(10 * TOKEN);
+    });
+
+    it("withdraws treasury tokens to the admin destination", async () => {
+      await withdrawToken(usdc, 4 * TOKEN);
+
+      const destination = await getAccount(
+        connection,
+        getAssociatedTokenAddressSync(usdc, tv.admin.publicKey)
+      );
+      expect(Number(destination.amount)).to.equal(4 * TOKEN);
+      const treasury = await getAccount(connection, treasuryAta(usdc));
+      expect(Number(treasury.amount)).to.equal(6 * TOKEN);
+    });
+
+    it("rejects withdrawing more than the treasury holds", async () => {
+      await expectError(() => withdrawToken(usdc, 7 * TOKEN), "InsufficientFunds");
+    });
+
+    it("rejects a disabled payment mint", async () => {
+      await setPaymentMint(usdc, 2, false);
+      await expectError(() => buyWithToken(usdc, TOKEN), "PaymentMintDisabled");
+    });
+
+    it("rejects a purchase that rounds down to zero VAL", async () => {
+      // One millionth of a VAL per token: one token unit buys a thousandth of the smallest VAL unit.
+      await setPaymentMint(usdc, 1, true, TOKEN_PROGRAM_ID, 1_000_000);
+
+      await expectError(() => buyWithToken(usdc, 1), "ZeroValOutput");
+      const treasury = await getAccount(connection, treasuryAta(usdc));
+      expect(Number(treasury.amount)).to.equal(6 * TOKEN);
+    });
+  });
+
+  describe("transfer fee mints", () => {
+    let feeMint: PublicKey;
+
+    before(async () => {
+      feeMint = await createTransferFeeMint(connection, tv.admin, 6, 100); // 1% fee
+      await fundTokenAccount(
+        connection,
+        tv.admin,
+        feeMint,
+        buyer.publicKey,
+        100 * TOKEN,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await setPaymentMint(feeMint, 2, true, TOKEN_2022_PROGRAM_ID);
+    });
+
+    it("prices VAL on the tokens the treasury received after the fee", async () => {
+      const before = await valBalance(buyer.publicKey);
+      await buyWithToken(feeMint, 10 * TOKEN, TOKEN_2022_PROGRAM_ID);
+
+      // 10 tokens less the 1% fee leaves 9.9 tokens, at 2 VAL per token = 19.8 VAL.
+      expect((await valBalance(buyer.publicKey)) - before).to.equal(19.8 * LAMPORTS_PER_SOL);
+      const record = await program.account.paymentMint.fetch(paymentMintPda(feeMint));
+      expect(record.totalReceived.toNumber()).to.equal(9.9 * TOKEN);
+    });
+  });
+});
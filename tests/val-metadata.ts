@@ -0,0 +1,96 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TestVault, createTestVault, expectError } from "./helpers";
+
+// Loaded from tests/fixtures/mpl_token_metadata.so by Anchor.toml.
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/** Reads name, symbol and URI from a Metaplex metadata account, trimming the padding. */
+function decodeMetadata(data: Buffer) {
+  let offset = 1 + 32 + 32; // key, update authority, mint
+  const readString = () => {
+    const len = data.readUInt32LE(offset);
+    const value = data.subarray(offset + 4, offset + 4 + len).toString("utf8");
+    offset += 4 + len;
+    return value.replace(/\0+$/, "");
+  };
+  return { name: readString(), symbol: readString(), uri: readString() };
+}
+
+describe("set_val_metadata", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+
+  let tv: TestVault;
+  let metadata: PublicKey;
+
+  function setMetadata(name: string, symbol: string, uri: string) {
+    return program.methods
+      .setValMetadata(name, symbol, uri)
+      .accountsPartial({
+        admin: tv.admin.publicKey,
+        vault: tv.vault,
+        valMint: tv.valMint,
+        mintAuthority: tv.mintAuthority,
+        metadata,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([tv.admin])
+      .rpc();
+  }
+
+  async function fetchMetadata() {
+    const account = await provider.connection.getAccountInfo(metadata);
+    expect(account.owner.toBase58()).to.equal(TOKEN_METADATA_PROGRAM_ID.toBase58());
+    return decodeMetadata(account.data);
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+    [metadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), tv.valMint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    );
+  });
+
+  it("creates the VAL metadata", async () => {
+    await setMetadata("Vault Token", "VAL", "https://example.com/val.json");
+    expect(await fetchMetadata()).to.deep.equal({
+      name: "Vault Token",
+      symbol: "VAL",
+      uri: "https://example.com/val.json",
+    });
+  });
+
+  it("updates the existing metadata", async () => {
+    await setMetadata("Vault Token v2", "VAL2", "https://example.com/val-v2.json");
+    expect(await fetchMetadata()).to.deep.equal({
+      name: "Vault Token v2",
+      symbol: "VAL2",
+      uri: "https://example.com/val-v2.json",
+    });
+  });
+
+  it("rejects an over-long name", async () => {
+    await expectError(() => setMetadata("N".repeat(33), "VAL", ""), "InvalidMetadata");
+  });
+
+  it("rejects an over-long symbol", async () => {
+    await expectError(() => setMetadata("Vault Token", "S".repeat(11), ""), "InvalidMetadata");
+  });
+
+  it("rejects an over-long URI", async () => {
+    await expectError(
+      () => setMetadata("Vault Token", "VAL", "https://example.com/" + "u".repeat(200)),
+      "InvalidMetadata"
+    );
+  });
+});
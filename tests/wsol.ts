@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { VaultProject } from "../target/types/vault_project";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  NATIVE_MINT,
+  TOKEN_PROGRAM_ID,
+  createWrappedNativeAccount,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { TestVault, airdropSol, createTestVault, expectError, pda } from "./helpers";
+
+describe("wSOL payments", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.vaultProject as Program<VaultProject>;
+  const connection = provider.connection;
+
+  let tv: TestVault;
+  let user: Keypair;
+  let userWsol: PublicKey;
+
+  function wsolTemp(owner: PublicKey) {
+    return pda(program, [Buffer.from("wsol_temp"), tv.vault.toBuffer(), owner.toBuffer()]);
+  }
+
+  function buyWsol(lamports: number, wsolAccount = userWsol) {
+    return program.methods
+      .buyValWsol(new anchor.BN(lamports))
+      .accountsPartial({
+        buy: {
+          user: user.publicKey,
+          vault: tv.vault,
+          treasury: tv.treasury,
+          valMint: tv.valMint,
+          mintAuthority: tv.mintAuthority,
+          userValAta: getAssociatedTokenAddressSync(tv.valMint, user.publicKey),
+          purchaseRecord: pda(program, [
+            Buffer.from("purchase"),
+            tv.vault.toBuffer(),
+            user.publicKey.toBuffer(),
+          ]),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        },
+        userWsolAccount: wsolAccount,
+        wsolTemp: wsolTemp(user.publicKey),
+        nativeMint: NATIVE_MINT,
+        wsolTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  function depositWsol(lamports: number) {
+    return program.methods
+      .depositWsolAndAutoRegister(new anchor.BN(lamports))
+      .accountsPartial({
+        deposit: {
+          depositor: user.publicKey,
+          vault: tv.vault,
+          child: pda(program, [Buffer.from("child"), tv.vault.toBuffer(), user.publicKey.toBuffer()]),
+          treasury: tv.treasury,
+          systemProgram: SystemProgram.programId,
+        },
+        depositorWsolAccount: userWsol,
+        wsolTemp: wsolTemp(user.publicKey),
+        nativeMint: NATIVE_MINT,
+        wsolTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function wsolBalance() {
+    return Number((await getAccount(connection, userWsol)).amount);
+  }
+
+  before(async () => {
+    tv = await createTestVault(program);
+    user = Keypair.generate();
+    await airdropSol(connection, user.publicKey, 10 * LAMPORTS_PER_SOL);
+    userWsol = await createWrappedNativeAccount(connection, user, user.publicKey, 4 * LAMPORTS_PER_SOL);
+  });
+
+  describe("buy_val_wsol", () => {
+    it("unwraps wSOL into the treasury and mints VAL", async () => {
+      const treasuryBefore = await connection.getBalance(tv.treasury);
+      await buyWsol(LAMPORTS_PER_SOL);
+
+      expect(await wsolBalance()).to.equal(3 * LAMPORTS_PER_SOL);
+      expect(await connection.getBalance(tv.treasury)).to.equal(treasuryBefore + LAMPORTS_PER_SOL);
+      const val = await getAccount(
+        connection,
+        getAssociatedTokenAddressSync(tv.valMint, user.publicKey)
+      );
+      expect(Number(val.amount)).to.equal(100 * LAMPORTS_PER_SOL);
+      // The temporary account is closed within the instruction.
+      expect(await connection.getAccountInfo(wsolTemp(user.publicKey))).to.be.null;
+    });
+
+    it("rejects a wSOL account the buyer does not own", async () => {
+      const other = Keypair.generate();
+      await airdropSol(connection, other.publicKey, LAMPORTS_PER_SOL);
+      const otherWsol = await createWrappedNativeAccount(
+        connection,
+        other,
+        other.publicKey,
+        LAMPORTS_PER_SOL / 2
+      );
+      await expectError(() => buyWsol(LAMPORTS_PER_SOL / 2, otherWsol), "ConstraintTokenOwner");
+    });
+  });
+
+  describe("deposit_wsol_and_auto_register", () => {
+    it("unwraps wSOL into the treasury and registers the child", async () => {
+      const treasuryBefore = await connection.getBalance(tv.treasury);
+      await depositWsol(LAMPORTS_PER_SOL);
+
+      expect(await wsolBalance()).to.equal(2 * LAMPORTS_PER_SOL);
+      expect(await connection.getBalance(tv.treasury)).to.equal(treasuryBefore + LAMPORTS_PER_SOL);
+      const child = await program.account.childAccount.fetch(
+        pda(program, [Buffer.from("child"), tv.vault.toBuffer(), user.publicKey.toBuffer()])
+      );
+      expect(child.authority.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(child.totalDeposited.toNumber()).to.equal(LAMPORTS_PER_SOL);
+    });
+
+    it("rejects a zero deposit", async () => {
+      await expectError(() => depositWsol(0), "InvalidAmount");
+    });
+  });
+});